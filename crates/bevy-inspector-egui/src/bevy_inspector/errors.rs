@@ -155,7 +155,7 @@ pub fn no_type_id(ui: &mut egui::Ui, component_name: &str) {
     ui.label(job);
 }
 
-pub fn name_of_type(type_id: TypeId, type_registry: &TypeRegistry) -> Cow<str> {
+pub fn name_of_type(type_id: TypeId, type_registry: &TypeRegistry) -> Cow<'_, str> {
     type_registry
         .get(type_id)
         .map(|registration| Cow::Borrowed(registration.type_info().type_path_table().short_path()))
@@ -37,24 +37,36 @@
 //! ```
 
 use std::any::TypeId;
+use std::sync::Arc;
 
 use bevy_asset::{Asset, AssetServer, Assets, ReflectAsset, UntypedAssetId};
+use bevy_core::Name;
+use bevy_ecs::prelude::*;
 use bevy_ecs::query::{QueryFilter, WorldQuery};
+use bevy_ecs::reflect::ReflectFromWorld;
 use bevy_ecs::system::CommandQueue;
-use bevy_ecs::{component::ComponentId, prelude::*};
-use bevy_hierarchy::{Children, Parent};
-use bevy_reflect::{Reflect, TypeRegistry};
+use bevy_hierarchy::{BuildWorldChildren, Children, DespawnRecursiveExt, Parent};
+use bevy_reflect::serde::TypedReflectSerializer;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, ReflectFromPtr, ReflectRef, TypeRegistry};
+use bevy_utils::{HashMap, Instant};
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
-use pretty_type_name::pretty_type_name;
 
 pub(crate) mod errors;
 
+/// The bevy version this crate is built against, shown in the "About" section of [`ui_for_world_entities`]
+const BEVY_VERSION: &str = "0.13";
+
 /// UI for displaying the entity hierarchy
 pub mod hierarchy;
 
+mod params;
+pub use params::*;
+
+use crate::egui_utils;
 use crate::reflect_inspector::{Context, InspectorUi};
 use crate::restricted_world_view::RestrictedWorldView;
+use crate::utils::pretty_type_name::pretty_type_name;
 
 /// Display a single [`&mut dyn Reflect`](bevy_reflect::Reflect).
 ///
@@ -71,6 +83,7 @@ pub fn ui_for_value(value: &mut dyn Reflect, ui: &mut egui::Ui, world: &mut Worl
     let mut cx = Context {
         world: Some(RestrictedWorldView::new(world)),
         queue: Some(&mut queue),
+        ..Default::default()
     };
     let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
     let changed = env.ui_for_reflect(value, ui);
@@ -80,27 +93,48 @@ pub fn ui_for_value(value: &mut dyn Reflect, ui: &mut egui::Ui, world: &mut Worl
 
 /// Display `Entities`, `Resources` and `Assets` using their respective functions inside headers
 pub fn ui_for_world(world: &mut World, ui: &mut egui::Ui) {
+    WorldInspectorParams::ui_scope(world, ui, ui_for_world_inner);
+}
+
+/// Like [`ui_for_world`], but using `params` instead of the [`WorldInspectorParams`] resource, for example to show
+/// multiple world inspectors with their own configuration and state
+pub fn ui_for_world_with_params(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    params: &mut WorldInspectorParams,
+) {
+    params.style_scope(ui, |ui, params| ui_for_world_inner(world, ui, params));
+}
+
+fn ui_for_world_inner(world: &mut World, ui: &mut egui::Ui, params: &mut WorldInspectorParams) {
     egui::CollapsingHeader::new("Entities")
         .default_open(true)
         .show(ui, |ui| {
-            ui_for_world_entities(world, ui);
+            ui_for_world_entities_inner(world, ui, params);
         });
     egui::CollapsingHeader::new("Resources").show(ui, |ui| {
-        ui_for_resources(world, ui);
+        ui_for_resources_inner(world, ui, params);
     });
     egui::CollapsingHeader::new("Assets").show(ui, |ui| {
-        ui_for_all_assets(world, ui);
+        ui_for_all_assets_inner(world, ui, params);
     });
 }
 
-/// Display all reflectable resources in the world
+/// Display all reflectable resources in the world, except for the ones in [`WorldInspectorParams::ignore_resources`]
 pub fn ui_for_resources(world: &mut World, ui: &mut egui::Ui) {
+    WorldInspectorParams::ui_scope(world, ui, |world, ui, params| {
+        ui_for_resources_inner(world, ui, params);
+    });
+}
+
+fn ui_for_resources_inner(world: &mut World, ui: &mut egui::Ui, params: &WorldInspectorParams) {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
     let type_registry = type_registry.read();
 
     let mut resources: Vec<_> = type_registry
         .iter()
         .filter(|registration| registration.data::<ReflectResource>().is_some())
+        .filter(|registration| !params.ignore_resources.contains(&registration.type_id()))
         .map(|registration| {
             (
                 registration.type_info().type_path_table().short_path(),
@@ -108,10 +142,10 @@ pub fn ui_for_resources(world: &mut World, ui: &mut egui::Ui) {
             )
         })
         .collect();
-    resources.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
+    resources.sort_by_key(|(name, ..)| *name);
     for (name, type_id) in resources {
         ui.collapsing(name, |ui| {
-            by_type_id::ui_for_resource(world, type_id, ui, name, &type_registry);
+            by_type_id::ui_for_resource_inner(world, type_id, ui, name, &type_registry, params);
         });
     }
 }
@@ -132,6 +166,7 @@ pub fn ui_for_resource<R: Resource + Reflect>(world: &mut World, ui: &mut egui::
     let mut cx = Context {
         world: Some(world_view),
         queue: Some(&mut queue),
+        ..Default::default()
     };
     let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
 
@@ -142,8 +177,25 @@ pub fn ui_for_resource<R: Resource + Reflect>(world: &mut World, ui: &mut egui::
     queue.apply(world);
 }
 
+/// Display the component `C` of the entity, see [`by_type_id::ui_for_component`]
+pub fn ui_for_component<C: Component + Reflect>(
+    world: &mut World,
+    entity: Entity,
+    ui: &mut egui::Ui,
+) -> bool {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    by_type_id::ui_for_component(world, entity, TypeId::of::<C>(), ui, &type_registry)
+}
+
 /// Display all reflectable assets
 pub fn ui_for_all_assets(world: &mut World, ui: &mut egui::Ui) {
+    WorldInspectorParams::ui_scope(world, ui, |world, ui, params| {
+        ui_for_all_assets_inner(world, ui, params);
+    });
+}
+
+fn ui_for_all_assets_inner(world: &mut World, ui: &mut egui::Ui, params: &WorldInspectorParams) {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
     let type_registry = type_registry.read();
 
@@ -157,10 +209,10 @@ pub fn ui_for_all_assets(world: &mut World, ui: &mut egui::Ui) {
             )
         })
         .collect();
-    assets.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
+    assets.sort_by_key(|(name, ..)| *name);
     for (name, type_id) in assets {
         ui.collapsing(name, |ui| {
-            by_type_id::ui_for_assets(world, type_id, ui, &type_registry);
+            by_type_id::ui_for_assets_inner(world, type_id, ui, &type_registry, params);
         });
     }
 }
@@ -184,10 +236,11 @@ pub fn ui_for_assets<A: Asset + Reflect>(world: &mut World, ui: &mut egui::Ui) {
     let mut cx = Context {
         world: Some(world_view),
         queue: Some(&mut queue),
+        ..Default::default()
     };
 
     let mut assets: Vec<_> = assets.iter_mut().collect();
-    assets.sort_by(|(a, _), (b, _)| a.cmp(b));
+    assets.sort_by_key(|(id, _)| *id);
     for (handle_id, asset) in assets {
         let id = egui::Id::new(handle_id);
 
@@ -223,6 +276,7 @@ pub fn ui_for_state<T: States + Reflect>(world: &mut World, ui: &mut egui::Ui) {
     let mut cx = Context {
         world: Some(world_view),
         queue: Some(&mut queue),
+        ..Default::default()
     };
     let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
 
@@ -235,52 +289,285 @@ pub fn ui_for_state<T: States + Reflect>(world: &mut World, ui: &mut egui::Ui) {
     queue.apply(world);
 }
 
-/// Display all entities and their components
+/// Display all entities and their components, grouped according to [`WorldInspectorParams::display_mode`]
 pub fn ui_for_world_entities(world: &mut World, ui: &mut egui::Ui) {
-    ui_for_world_entities_filtered::<Without<Parent>>(world, ui, true);
+    WorldInspectorParams::ui_scope(world, ui, ui_for_world_entities_inner);
 }
 
-#[derive(Debug, Clone)]
-struct Filter {
-    word: String,
-    is_fuzzy: bool,
+fn ui_for_world_entities_inner(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    params: &mut WorldInspectorParams,
+) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    params.sync_ignore_list(&type_registry);
+    params.sync_snapshot(world, &type_registry);
+    drop(type_registry);
+    if params.show_world_stats {
+        world_stats_ui(world, ui);
+    }
+    if let Some(entity) = params.focused_entity {
+        if world.get_entity(entity).is_some() {
+            return focused_entity_ui(world, entity, ui, params);
+        }
+        params.focused_entity = None;
+    }
+    ui.horizontal(|ui| {
+        ui.selectable_value(
+            &mut params.display_mode,
+            DisplayMode::Hierarchy,
+            "Hierarchy",
+        );
+        ui.selectable_value(
+            &mut params.display_mode,
+            DisplayMode::ByArchetype,
+            "By Archetype",
+        );
+        ui.selectable_value(&mut params.display_mode, DisplayMode::Flat, "Flat");
+        ui.separator();
+        ui.toggle_value(&mut params.frozen, "❄ Freeze")
+            .on_hover_text("Keep showing the current component values instead of updating them");
+        ui.menu_button("⚙", |ui| params.settings_ui(ui))
+            .response
+            .on_hover_text("Settings");
+    });
+
+    if !params.read_only && ui.button("➕ Spawn entity").clicked() {
+        params.spawn_entity(world);
+    }
+    params.expand_collapse_ui(ui);
+    let filter = Filter::from_ui(ui, params);
+
+    params.keyboard_navigation_ui(ui);
+    InspectorSelection::reveal_changed(world, params);
+    pinned_entities_ui(world, ui, &filter, params);
+    compare_entities_ui(world, ui, params);
+
+    match params.display_mode {
+        DisplayMode::Hierarchy if params.flat_search_results && !filter.word.is_empty() => {
+            flat_search_results_ui(world, ui, &filter, params);
+        }
+        DisplayMode::Hierarchy => {
+            ui_for_world_entities_filtered_inner::<Without<Parent>>(
+                world, ui, true, &filter, params,
+            );
+            orphaned_entities_ui(world, ui, &filter, params);
+            root_drop_zone_ui(world, ui, params);
+        }
+        DisplayMode::ByArchetype => {
+            ui_for_world_entities_by_archetype(world, ui, &filter, params);
+        }
+        DisplayMode::Flat => {
+            ui_for_world_entities_filtered_inner::<()>(world, ui, false, &filter, params);
+        }
+    }
+
+    InspectorSelection::update(world, ui.ctx().frame_nr(), params);
+    params
+        .last_changed
+        .retain(|&(entity, _), _| world.get_entity(entity).is_some());
+    about_ui(world, ui);
 }
 
-impl Filter {
-    fn from_ui(ui: &mut egui::Ui) -> Self {
-        let word = {
-            // filter, using eguis memory and a hardcoded id
-            let filter_id = egui::Id::new("world ui filter word");
-            let mut filter = ui.memory_mut(|mem| {
-                let filter: &mut String = mem.data.get_persisted_mut_or_default(filter_id);
-                filter.clone()
-            });
-            ui.text_edit_singleline(&mut filter);
-            ui.memory_mut(|mem| {
-                *mem.data.get_persisted_mut_or_default(filter_id) = filter.clone();
-            });
+/// The versions of this crate and the bevy version it is built for, collapsed by default, to include when reporting issues
+fn about_ui(world: &World, ui: &mut egui::Ui) {
+    ui.separator();
+    egui::CollapsingHeader::new("About")
+        .id_source("world ui about")
+        .show(ui, |ui| {
+            let registered_types = world.resource::<AppTypeRegistry>().read().iter().count();
+            ui.label(format!(
+                "bevy-inspector-egui {} for bevy {BEVY_VERSION}",
+                env!("CARGO_PKG_VERSION")
+            ));
+            ui.label(format!(
+                "{} entities, {} archetypes, {} component types, {registered_types} registered types",
+                world.entities().len(),
+                world.archetypes().len(),
+                world.components().len(),
+            ));
+        });
+}
 
-            // improves overall matching
-            let filter = filter.to_lowercase();
+/// Shows only the [`focused_entity`](WorldInspectorParams::focused_entity) including its children
+fn focused_entity_ui(
+    world: &mut World,
+    entity: Entity,
+    ui: &mut egui::Ui,
+    params: &mut WorldInspectorParams,
+) {
+    if ui.button("⬅ Back to all").clicked() {
+        params.focused_entity = None;
+    }
+    ui.separator();
 
-            filter
-        };
+    ui_for_entity_with_children_scoped(world, entity, ui, params);
+}
 
-        // filter kind
-        let is_fuzzy = {
-            let filter_kind_id = egui::Id::new("world ui filter fuzzy");
-            let mut is_fuzzy = ui.memory_mut(|mem| {
-                let fuzzy: &mut bool = mem.data.get_persisted_mut_or_default(filter_kind_id);
-                fuzzy.clone()
-            });
-            ui.checkbox(&mut is_fuzzy, "Fuzzy Match");
-            ui.memory_mut(|mem| {
-                *mem.data.get_persisted_mut_or_default(filter_kind_id) = is_fuzzy.clone();
-            });
-            is_fuzzy
+/// Lists every entity matching the filter with its ancestors. Clicking a result focuses it.
+fn flat_search_results_ui(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    filter: &Filter,
+    params: &mut WorldInspectorParams,
+) {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    let mut entities = world.query::<Entity>().iter(world).collect::<Vec<_>>();
+    entities.retain(|&entity| {
+        !params.is_entity_ignored(world, entity)
+            && entity_satisfies_filter(world, entity, filter, &type_registry, params)
+    });
+    entities.sort();
+
+    ui.label(egui::RichText::new(format!("{} results", entities.len())).weak());
+    for entity in entities {
+        let response = ui.selectable_label(false, entity_breadcrumb(world, entity, params));
+        entity_header_response(ui, entity, &response, params);
+        if response.clicked() {
+            params.focused_entity = Some(entity);
+        }
+    }
+}
+
+/// The names of the entity and its ancestors, starting at the root, like `World > Level > Enemy (5v0)`
+fn entity_breadcrumb(world: &World, entity: Entity, params: &WorldInspectorParams) -> String {
+    let mut path = vec![params.entity_name(world, entity)];
+    let mut current = entity;
+    while let Some(parent) = world.get::<Parent>(current) {
+        current = parent.get();
+        // guard against cycles in broken hierarchies
+        if current == entity || path.len() > 1024 {
+            break;
+        }
+        path.push(params.entity_name_without_id(world, current).into_owned());
+    }
+    path.reverse();
+    path.join(" > ")
+}
+
+/// The names of the entity and its ancestors joined by `/`, like `Level/Enemy[1]/Weapon`.
+///
+/// Entities without a [`Name`] are represented by their id. When siblings share a name, the index among them is appended.
+fn entity_path(world: &World, entity: Entity) -> String {
+    let mut path = Vec::new();
+    let mut current = Some(entity);
+    while let Some(entity) = current {
+        // guard against cycles in broken hierarchies
+        if path.len() > 1024 {
+            break;
+        }
+        let parent = world.get::<Parent>(entity).map(Parent::get);
+        let segment = match world.get::<Name>(entity) {
+            Some(name) => {
+                let siblings: Vec<Entity> = match parent {
+                    Some(parent) => world
+                        .get::<Children>(parent)
+                        .map(|children| children.to_vec())
+                        .unwrap_or_default(),
+                    None => world
+                        .iter_entities()
+                        .filter(|sibling| !sibling.contains::<Parent>())
+                        .map(|sibling| sibling.id())
+                        .collect(),
+                };
+                let mut same_name: Vec<_> = siblings
+                    .into_iter()
+                    .filter(|&sibling| world.get::<Name>(sibling) == Some(name))
+                    .collect();
+                if parent.is_none() {
+                    // root entities are iterated by archetype, so order them by id instead
+                    same_name.sort();
+                }
+                match same_name.iter().position(|&sibling| sibling == entity) {
+                    Some(index) if same_name.len() > 1 => format!("{name}[{index}]"),
+                    _ => name.to_string(),
+                }
+            }
+            None => format!("{entity:?}"),
         };
+        path.push(segment);
+        current = parent;
+    }
+    path.reverse();
+    path.join("/")
+}
+
+/// A one-line overview of the number of entities, root entities, archetypes and component types
+fn world_stats_ui(world: &mut World, ui: &mut egui::Ui) {
+    let entities = world.entities().len();
+    let roots = world
+        .query_filtered::<(), Without<Parent>>()
+        .iter(world)
+        .count();
+    let archetypes = world.archetypes().len();
+    let components = world.components().len();
+    ui.label(
+        egui::RichText::new(format!(
+            "{entities} entities ({roots} roots), {archetypes} archetypes, {components} component types"
+        ))
+        .weak(),
+    );
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Filter {
+    word: String,
+    is_fuzzy: bool,
+    component_filter_changed: bool,
+    /// Only keep entities with a component matching the component filter
+    by_component: bool,
+    /// Remove entities whose components are all ignored
+    hide_empty: bool,
+    /// Only keep entities without a `Name`
+    only_unnamed: bool,
+}
+
+impl Filter {
+    fn from_ui(ui: &mut egui::Ui, params: &mut WorldInspectorParams) -> Self {
+        ui.add(egui::TextEdit::singleline(&mut params.entity_filter).hint_text("Filter entities"));
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut params.fuzzy_filter, "Fuzzy Match");
+            ui.checkbox(&mut params.only_unnamed_entities, "Only unnamed");
+            ui.checkbox(&mut params.flat_search_results, "Flat results");
+        });
+        let component_filter_changed = ui
+            .add(
+                egui::TextEdit::singleline(&mut params.component_filter)
+                    .hint_text("Filter components"),
+            )
+            .changed();
+        params.component_filter_changed = component_filter_changed;
+        ui.checkbox(&mut params.match_field_names, "Match field names");
+        ui.checkbox(
+            &mut params.filter_entities_by_component,
+            "Hide entities without matching components",
+        );
+        ui.checkbox(&mut params.show_only_changed, "Only changed components");
+        if !params.ignore_components.is_empty() {
+            ui.checkbox(
+                &mut params.show_ignored_components,
+                "Show ignored components",
+            );
+        }
 
-        Filter { word, is_fuzzy }
+        Filter::from_params(params, component_filter_changed)
+    }
+
+    /// The filter described by the current [`WorldInspectorParams`]
+    fn from_params(params: &WorldInspectorParams, component_filter_changed: bool) -> Self {
+        Filter {
+            // improves overall matching
+            word: params.entity_filter.to_lowercase(),
+            is_fuzzy: params.fuzzy_filter,
+            component_filter_changed,
+            by_component: params.filter_entities_by_component
+                && !params.component_filter.is_empty(),
+            hide_empty: params.hide_empty_entities,
+            only_unnamed: params.only_unnamed_entities,
+        }
     }
 
     /// empty filter which does nothing
@@ -288,103 +575,1048 @@ impl Filter {
         Self {
             word: String::from(""),
             is_fuzzy: false,
+            component_filter_changed: false,
+            by_component: false,
+            hide_empty: false,
+            only_unnamed: false,
         }
     }
 
+    fn is_empty(&self) -> bool {
+        self.word.is_empty() && !self.by_component && !self.hide_empty && !self.only_unnamed
+    }
+
     /// filter entities based on internal state
-    fn filter_entities(&self, world: &mut World, entities: &mut Vec<Entity>) {
-        if self.word.is_empty() {
+    fn filter_entities(
+        &self,
+        world: &mut World,
+        entities: &mut Vec<Entity>,
+        type_registry: &TypeRegistry,
+        params: &mut WorldInspectorParams,
+    ) {
+        entities.retain(|&entity| !params.is_entity_ignored(world, entity));
+        if self.is_empty() {
             return;
         }
 
         entities.retain(|entity| {
-            self_or_children_satisfy_filter(world, *entity, self.word.as_str(), self.is_fuzzy)
+            self_or_children_satisfy_filter(world, *entity, self, type_registry, params)
         });
     }
 }
 
+/// The root entities listed by [`ui_for_world_entities`] in the [`Hierarchy`](DisplayMode::Hierarchy) mode, in the same order.
+///
+/// Applies the entity and component filters and the ignored entities of the [`WorldInspectorParams`], so that tools can
+/// walk the same entities as the world inspector. Children can be reached through their [`Children`] component.
+pub fn inspectable_entities(world: &mut World) -> impl Iterator<Item = Entity> {
+    WorldInspectorParams::scope(world, |world, params| {
+        let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+        let type_registry = type_registry.read();
+
+        let mut entities = world
+            .query_filtered::<Entity, Without<Parent>>()
+            .iter(world)
+            .collect::<Vec<_>>();
+        if let Some(root_entity_filter) = &params.root_entity_filter {
+            entities.retain(|&entity| root_entity_filter(world, entity));
+        }
+        Filter::from_params(params, false).filter_entities(
+            world,
+            &mut entities,
+            &type_registry,
+            params,
+        );
+        entities.sort();
+        entities
+    })
+    .into_iter()
+}
+
 /// Display all entities matching the given filter
 pub fn ui_for_world_entities_filtered<F: WorldQuery + QueryFilter>(
     world: &mut World,
     ui: &mut egui::Ui,
     with_children: bool,
 ) {
-    let filter = Filter::from_ui(ui);
+    WorldInspectorParams::ui_scope(world, ui, |world, ui, params| {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        params.sync_ignore_list(&type_registry);
+        params.sync_snapshot(world, &type_registry);
+        drop(type_registry);
+        params.expand_collapse_ui(ui);
+        let filter = Filter::from_ui(ui, params);
+        params.keyboard_navigation_ui(ui);
+        InspectorSelection::reveal_changed(world, params);
+
+        ui_for_world_entities_filtered_inner::<F>(world, ui, with_children, &filter, params);
+        InspectorSelection::update(world, ui.ctx().frame_nr(), params);
+    });
+}
 
+fn ui_for_world_entities_filtered_inner<F: WorldQuery + QueryFilter>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    with_children: bool,
+    filter: &Filter,
+    params: &mut WorldInspectorParams,
+) {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
     let type_registry = type_registry.read();
 
-    let mut root_entities = world.query_filtered::<Entity, F>();
-    let mut entities = root_entities.iter(world).collect::<Vec<_>>();
-    filter.filter_entities(world, &mut entities);
-    entities.sort();
+    // reuse the filtered entity list for `update_interval` as long as the filter stays the same
+    let root_entity_filter = params
+        .root_entity_filter
+        .as_ref()
+        .map(|filter| Arc::as_ptr(filter).cast::<()>());
+    let cache_id = egui::Id::new((ui.id(), std::any::type_name::<F>(), root_entity_filter));
+    let cached = params.update_interval.and_then(|interval| {
+        params
+            .entity_lists
+            .get(&cache_id)
+            .filter(|cached| cached.filter == *filter && cached.updated.elapsed() < interval)
+            .map(|cached| cached.entities.clone())
+    });
+    let entities = match cached {
+        Some(mut entities) => {
+            entities.retain(|&entity| world.get_entity(entity).is_some());
+            entities
+        }
+        None => {
+            let mut root_entities = world.query_filtered::<Entity, F>();
+            let mut entities = root_entities.iter(world).collect::<Vec<_>>();
+            if let Some(root_entity_filter) = &params.root_entity_filter {
+                entities.retain(|&entity| root_entity_filter(world, entity));
+            }
+            filter.filter_entities(world, &mut entities, &type_registry, params);
+            entities.sort();
+
+            if params.update_interval.is_some() {
+                params.entity_lists.insert(
+                    cache_id,
+                    CachedEntityList {
+                        updated: Instant::now(),
+                        filter: filter.clone(),
+                        entities: entities.clone(),
+                    },
+                );
+            }
+            entities
+        }
+    };
 
     let id = egui::Id::new("world ui");
     for entity in entities {
-        let id = id.with(entity);
+        ui_for_world_entity(
+            world,
+            entity,
+            ui,
+            id.with(entity),
+            with_children,
+            &type_registry,
+            filter,
+            params,
+        );
+    }
+
+    despawn_pending_entities(world, params);
+    reparent_pending_entity(world, params);
+}
+
+fn ui_for_world_entities_by_archetype(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    filter: &Filter,
+    params: &mut WorldInspectorParams,
+) {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    let mut archetypes: Vec<_> = world
+        .archetypes()
+        .iter()
+        .filter(|archetype| !archetype.is_empty())
+        .map(|archetype| {
+            let entities: Vec<_> = archetype.entities().iter().map(|e| e.id()).collect();
+            (archetype.id(), entities)
+        })
+        .collect();
+    for (_, entities) in &mut archetypes {
+        if let Some(root_entity_filter) = &params.root_entity_filter {
+            entities.retain(|&entity| root_entity_filter(world, entity));
+        }
+        entities.retain(|&entity| !params.is_entity_ignored(world, entity));
+        if !filter.is_empty() {
+            entities.retain(|&entity| {
+                entity_satisfies_filter(world, entity, filter, &type_registry, params)
+            });
+        }
+        entities.sort();
+    }
+    // the largest archetypes first, so that many archetypes with few entities stand out at the bottom
+    archetypes.sort_by(|(a_id, a), (b_id, b)| b.len().cmp(&a.len()).then(a_id.cmp(b_id)));
 
-        let entity_name = guess_entity_name(world, entity);
+    let id = egui::Id::new("world ui by archetype");
+    for (archetype_id, entities) in archetypes {
+        let Some(&first) = entities.first() else {
+            continue;
+        };
 
-        egui::CollapsingHeader::new(&entity_name)
+        let components = components_of_entity_cached(&mut world.into(), first, params)
+            .unwrap_or_else(|| Arc::new([]));
+        let size: usize = components.iter().map(|&(.., size)| size).sum();
+
+        let id = id.with(archetype_id);
+        let header = format!(
+            "Archetype {} ({} entities, {} components, {size} bytes per entity)",
+            archetype_id.index(),
+            entities.len(),
+            components.len(),
+        );
+        egui::CollapsingHeader::new(header)
             .id_source(id)
             .show(ui, |ui| {
-                if with_children {
-                    ui_for_entity_with_children_inner(
+                let component_names: Vec<_> =
+                    components.iter().map(|(name, ..)| name.as_str()).collect();
+                ui.weak(component_names.join(", "));
+
+                for entity in entities {
+                    ui_for_world_entity(
                         world,
                         entity,
                         ui,
-                        id,
+                        id.with(entity),
+                        false,
                         &type_registry,
-                        &filter,
+                        filter,
+                        params,
                     );
-                } else {
-                    let mut queue = CommandQueue::default();
-                    ui_for_entity_components(
-                        &mut world.into(),
-                        Some(&mut queue),
-                        entity,
-                        ui,
-                        id,
-                        &type_registry,
-                    );
-                    queue.apply(world);
                 }
             });
     }
+
+    despawn_pending_entities(world, params);
+    reparent_pending_entity(world, params);
 }
 
-fn self_or_children_satisfy_filter(
+/// Display the entities in [`WorldInspectorParams::pinned`] in their own section
+fn pinned_entities_ui(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    filter: &Filter,
+    params: &mut WorldInspectorParams,
+) {
+    params
+        .pinned
+        .retain(|&entity| world.get_entity(entity).is_some());
+    if params.pinned.is_empty() {
+        return;
+    }
+
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    let id = egui::Id::new("world ui pinned");
+    egui::CollapsingHeader::new("Pinned")
+        .id_source(id)
+        .default_open(true)
+        .show(ui, |ui| {
+            for entity in params.pinned.clone() {
+                ui_for_world_entity(
+                    world,
+                    entity,
+                    ui,
+                    id.with(entity),
+                    false,
+                    &type_registry,
+                    filter,
+                    params,
+                );
+            }
+        });
+    ui.separator();
+}
+
+/// The components shared by all entities of the [`multi_selection`](WorldInspectorParams::multi_selection), with one column per entity
+fn compare_entities_ui(world: &mut World, ui: &mut egui::Ui, params: &mut WorldInspectorParams) {
+    params
+        .multi_selection
+        .retain(|&entity| world.get_entity(entity).is_some());
+    if params.multi_selection.len() < 2 {
+        return;
+    }
+
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    let entities: Vec<_> = params
+        .multi_selection
+        .iter()
+        .map(|&entity| world.entity(entity))
+        .collect();
+
+    let mut components: Vec<_> = entities[0]
+        .archetype()
+        .components()
+        .filter(|&component_id| {
+            entities
+                .iter()
+                .all(|entity| entity.contains_id(component_id))
+        })
+        .filter_map(|component_id| world.components().get_info(component_id)?.type_id())
+        .filter(|type_id| !params.is_component_ignored(Some(*type_id)))
+        .filter_map(|type_id| {
+            let registration = type_registry.get(type_id)?;
+            registration.data::<ReflectComponent>()?;
+            let name = match params.display_names.get(&type_id) {
+                Some(name) => name.clone(),
+                None => crate::utils::pretty_type_name::pretty_type_name_str(
+                    registration.type_info().type_path(),
+                ),
+            };
+            Some((name, type_id))
+        })
+        .filter(|(name, _)| params.component_matches(name))
+        .collect();
+    components.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let id = egui::Id::new("world ui compare");
+    egui::CollapsingHeader::new(format!("Compare ({})", entities.len()))
+        .id_source(id)
+        .default_open(true)
+        .show(ui, |ui| {
+            if ui.small_button("Clear selection").clicked() {
+                params.multi_selection.clear();
+            }
+            egui::ScrollArea::horizontal().show(ui, |ui| {
+                egui::Grid::new(id.with("grid"))
+                    .num_columns(entities.len() + 1)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.label("");
+                        for entity in &entities {
+                            ui.strong(params.entity_name(world, entity.id()));
+                        }
+                        ui.end_row();
+
+                        let mut cx = Context {
+                            number_defaults: params.number_defaults(),
+                            world_inspector_params: Some(params),
+                            ..Default::default()
+                        };
+                        let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
+                        for (name, type_id) in &components {
+                            ui.label(name);
+                            let reflect_component = type_registry
+                                .get_type_data::<ReflectComponent>(*type_id)
+                                .unwrap();
+                            for entity in &entities {
+                                let Some(value) = reflect_component.reflect(*entity) else {
+                                    ui.label("");
+                                    continue;
+                                };
+                                env.ui_for_reflect_readonly_with_options(
+                                    value,
+                                    ui,
+                                    id.with((entity.id(), type_id)),
+                                    &(),
+                                );
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+        });
+    ui.separator();
+}
+
+/// Entities with a [`Parent`] which doesn't exist anymore, which wouldn't show up in the hierarchy otherwise
+fn orphaned_entities_ui(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    filter: &Filter,
+    params: &mut WorldInspectorParams,
+) {
+    let mut orphans = world
+        .query::<(Entity, &Parent)>()
+        .iter(world)
+        .filter(|(_, parent)| world.get_entity(parent.get()).is_none())
+        .map(|(entity, _)| entity)
+        .collect::<Vec<_>>();
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    filter.filter_entities(world, &mut orphans, &type_registry.read(), params);
+    if orphans.is_empty() {
+        return;
+    }
+    orphans.sort();
+
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    ui.separator();
+    let id = egui::Id::new("world ui orphaned");
+    egui::CollapsingHeader::new(format!("Orphaned ({})", orphans.len()))
+        .id_source(id)
+        .show(ui, |ui| {
+            for entity in orphans {
+                ui_for_world_entity(
+                    world,
+                    entity,
+                    ui,
+                    id.with(entity),
+                    true,
+                    &type_registry,
+                    filter,
+                    params,
+                );
+            }
+        });
+
+    despawn_pending_entities(world, params);
+    reparent_pending_entity(world, params);
+}
+
+/// Display the collapsible header of an entity in the world inspector
+#[allow(clippy::too_many_arguments)]
+fn ui_for_world_entity(
     world: &mut World,
     entity: Entity,
-    filter: &str,
-    is_fuzzy: bool,
-) -> bool {
-    let name = guess_entity_name(world, entity);
-    let self_matches = if is_fuzzy {
-        let matcher = SkimMatcherV2::default();
-        matcher.fuzzy_match(name.as_str(), filter).is_some()
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    with_children: bool,
+    type_registry: &TypeRegistry,
+    filter: &Filter,
+    params: &mut WorldInspectorParams,
+) {
+    let entity_name = entity_header_text(ui, world, entity, type_registry, params);
+
+    // expand the entities containing matching components when the component filter is edited
+    let expand_matching = filter.component_filter_changed
+        && !params.component_filter.is_empty()
+        && has_matching_component(world, entity, type_registry, params);
+
+    let mut header = navigable_entity_header(
+        params.entity_header(egui::CollapsingHeader::new(entity_name).id_source(id)),
+        entity,
+        params,
+    );
+    if expand_matching {
+        header = header.open(Some(true));
+    }
+    let response = header.show(ui, |ui| {
+        if with_children {
+            ui_for_entity_with_children_inner(world, entity, ui, id, type_registry, filter, params);
+        } else {
+            let mut queue = CommandQueue::default();
+            ui_for_entity_components(
+                &mut world.into(),
+                Some(&mut queue),
+                entity,
+                ui,
+                id,
+                type_registry,
+                params,
+            );
+            entity_actions_ui(ui, world, entity, id, type_registry, &mut queue, params);
+            queue.apply(world);
+        }
+    });
+    entity_header_response(ui, entity, &response.header_response, params);
+}
+
+/// Registers the header of an entity for the keyboard navigation, and expands or collapses it if requested.
+///
+/// The ancestors of a newly selected entity are expanded, see [`InspectorSelection`].
+fn navigable_entity_header(
+    mut header: egui::CollapsingHeader,
+    entity: Entity,
+    params: &mut WorldInspectorParams,
+) -> egui::CollapsingHeader {
+    if let Some((_, ancestors)) = &params.reveal {
+        if ancestors.contains(&entity) {
+            header = header.open(Some(true));
+        }
+    }
+    if params.spawned_entity == Some(entity) {
+        header = header.open(Some(true));
+    }
+    if !params.keyboard_navigation {
+        return header;
+    }
+    params.navigation.visible.push(entity);
+    if params.navigation.focused == Some(entity) {
+        if let Some(open) = params.navigation.open {
+            header = header.open(Some(open));
+        }
+    }
+    header
+}
+
+/// Tracks the hovered and clicked entity, and highlights the entity under the keyboard navigation cursor
+fn entity_header_response(
+    ui: &egui::Ui,
+    entity: Entity,
+    response: &egui::Response,
+    params: &mut WorldInspectorParams,
+) {
+    if response.hovered() {
+        params.hovered_entity = Some(entity);
+    }
+    if response.clicked() && ui.input(|input| input.modifiers.command) {
+        match params.multi_selection.iter().position(|&e| e == entity) {
+            Some(i) => {
+                params.multi_selection.remove(i);
+            }
+            None => params.multi_selection.push(entity),
+        }
+    } else if response.clicked() {
+        params.clicked_entity = Some(entity);
+        params.navigation.focused = Some(entity);
+    }
+    // drag the header onto another entity to reparent it
+    if !params.read_only {
+        let drag = response.interact(egui::Sense::drag());
+        drag.dnd_set_drag_payload(entity);
+        if response
+            .dnd_hover_payload::<Entity>()
+            .is_some_and(|dragged| *dragged != entity)
+        {
+            ui.painter()
+                .rect_stroke(response.rect, 2.0, ui.visuals().selection.stroke);
+        }
+        if let Some(dragged) = response.dnd_release_payload::<Entity>() {
+            if *dragged != entity {
+                params.pending_reparent = Some((*dragged, Some(entity)));
+            }
+        }
+    }
+    if params.multi_selection.contains(&entity) {
+        ui.painter()
+            .rect_stroke(response.rect, 2.0, ui.visuals().selection.stroke);
+    }
+
+    if params
+        .reveal
+        .as_ref()
+        .is_some_and(|&(selected, _)| selected == entity)
+    {
+        response.scroll_to_me(Some(egui::Align::Center));
+    }
+
+    if params.keyboard_navigation && params.navigation.focused == Some(entity) {
+        ui.painter()
+            .rect_stroke(response.rect, 2.0, ui.visuals().selection.stroke);
+        if params.navigation.scroll {
+            response.scroll_to_me(None);
+        }
+        if params.navigation.focus_header {
+            response.request_focus();
+        }
+    }
+}
+
+/// The "Add Component" and "Despawn" buttons of an entity
+fn entity_actions_ui(
+    ui: &mut egui::Ui,
+    world: &World,
+    entity: Entity,
+    id: egui::Id,
+    type_registry: &TypeRegistry,
+    queue: &mut CommandQueue,
+    params: &mut WorldInspectorParams,
+) {
+    if params.read_only {
+        return;
+    }
+
+    ui.horizontal(|ui| {
+        let pinned = params.pinned.contains(&entity);
+        if ui
+            .button(if pinned { "📌 Unpin" } else { "📌 Pin" })
+            .clicked()
+        {
+            if pinned {
+                params.pinned.retain(|&pinned| pinned != entity);
+            } else {
+                params.pinned.push(entity);
+            }
+        }
+        if params.focused_entity != Some(entity) && ui.button("🔍 Focus").clicked() {
+            params.focused_entity = Some(entity);
+        }
+        add_component_ui(ui, world, entity, id, type_registry, queue);
+        if ui
+            .button("📋 Copy path")
+            .on_hover_text(
+                "Copy the names of the entity and its ancestors, like `Level/Enemy[1]/Weapon`",
+            )
+            .clicked()
+        {
+            let path = entity_path(world, entity);
+            ui.output_mut(|output| output.copied_text = path);
+        }
+        if ui.button("📋 Export JSON").clicked() {
+            let json = entity_to_json(world, entity, type_registry);
+            ui.output_mut(|output| output.copied_text = json);
+        }
+        if ui.button("⧉ Duplicate").clicked() {
+            let with_children = params.duplicate_children;
+            queue.push(move |world: &mut World| {
+                duplicate_entity(world, entity, with_children);
+            });
+        }
+        if egui_utils::label_button(ui, "✖ Despawn", egui::Color32::RED) {
+            params.pending_despawns.push(entity);
+        }
+    });
+}
+
+/// Dropdown of all components which can be constructed through their [`ReflectDefault`] or [`ReflectFromWorld`] type data.
+///
+/// Components the entity already has are shown, but disabled.
+fn add_component_ui(
+    ui: &mut egui::Ui,
+    world: &World,
+    entity: Entity,
+    id: egui::Id,
+    type_registry: &TypeRegistry,
+    queue: &mut CommandQueue,
+) {
+    let Some(entity_ref) = world.get_entity(entity) else {
+        return;
+    };
+
+    let mut components: Vec<_> = type_registry
+        .iter()
+        .filter(|registration| {
+            registration.data::<ReflectComponent>().is_some()
+                && (registration.data::<ReflectDefault>().is_some()
+                    || registration.data::<ReflectFromWorld>().is_some())
+        })
+        .map(|registration| {
+            (
+                registration.type_info().type_path_table().short_path(),
+                registration.type_id(),
+            )
+        })
+        .collect();
+    components.sort_by_key(|(name, ..)| *name);
+
+    egui::ComboBox::from_id_source(id.with("add component"))
+        .selected_text("Add Component")
+        .show_ui(ui, |ui| {
+            for (name, type_id) in components {
+                let enabled = !entity_ref.contains_type_id(type_id);
+                if ui
+                    .add_enabled(enabled, egui::SelectableLabel::new(false, name))
+                    .clicked()
+                {
+                    queue.push(move |world: &mut World| {
+                        insert_default_component(world, entity, type_id)
+                    });
+                }
+            }
+        });
+}
+
+fn insert_default_component(world: &mut World, entity: Entity, type_id: TypeId) {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    let Some(registration) = type_registry.get(type_id) else {
+        return;
+    };
+    let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+        return;
+    };
+    let component = if let Some(reflect_default) = registration.data::<ReflectDefault>() {
+        reflect_default.default()
+    } else if let Some(reflect_from_world) = registration.data::<ReflectFromWorld>() {
+        reflect_from_world.from_world(world)
     } else {
-        name.to_lowercase().contains(&filter)
+        return;
+    };
+
+    if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+        reflect_component.insert(&mut entity_mut, &*component, &type_registry);
+    }
+}
+
+/// Serializes the reflected components of the entity as a JSON object, keyed by their type path.
+///
+/// Components which can't be serialized are listed under `"skipped"`.
+fn entity_to_json(world: &World, entity: Entity, type_registry: &TypeRegistry) -> String {
+    let Some(entity_ref) = world.get_entity(entity) else {
+        return String::new();
+    };
+
+    let mut components = serde_json::Map::new();
+    let mut skipped = Vec::new();
+    for component_id in entity_ref.archetype().components() {
+        let Some(info) = world.components().get_info(component_id) else {
+            continue;
+        };
+        let value = info
+            .type_id()
+            .and_then(|type_id| type_registry.get_type_data::<ReflectComponent>(type_id))
+            .and_then(|reflect_component| reflect_component.reflect(entity_ref))
+            .and_then(|value| {
+                serde_json::to_value(TypedReflectSerializer::new(value, type_registry)).ok()
+            });
+        match value {
+            Some(value) => {
+                components.insert(info.name().to_string(), value);
+            }
+            None => skipped.push(serde_json::Value::from(info.name())),
+        }
+    }
+
+    let json = serde_json::json!({
+        "entity": format!("{entity:?}"),
+        "components": components,
+        "skipped": skipped,
+    });
+    serde_json::to_string_pretty(&json).unwrap_or_default()
+}
+
+/// Spawns a copy of the entity next to it, with `(copy)` appended to its [`Name`], see [`copy_entity`]
+fn duplicate_entity(world: &mut World, entity: Entity, with_children: bool) -> Option<Entity> {
+    let copy = copy_entity(world, entity, with_children)?;
+    if let Some(name) = world.get::<Name>(entity) {
+        let name = Name::new(format!("{name} (copy)"));
+        world.entity_mut(copy).insert(name);
+    }
+    if let Some(parent) = world.get::<Parent>(entity).map(Parent::get) {
+        world.entity_mut(parent).add_child(copy);
+    }
+    Some(copy)
+}
+
+/// Spawns a copy of all reflected components of the entity, and optionally of its children.
+///
+/// The hierarchy components aren't copied, so the copy is spawned without a parent.
+fn copy_entity(world: &mut World, entity: Entity, with_children: bool) -> Option<Entity> {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    let entity_ref = world.get_entity(entity)?;
+    let components: Vec<_> = entity_ref
+        .archetype()
+        .components()
+        .filter_map(|component_id| {
+            let type_id = world.components().get_info(component_id)?.type_id()?;
+            if type_id == TypeId::of::<Parent>() || type_id == TypeId::of::<Children>() {
+                return None;
+            }
+            let reflect_component = type_registry.get_type_data::<ReflectComponent>(type_id)?;
+            let value = reflect_component.reflect(entity_ref)?.clone_value();
+            Some((reflect_component.clone(), value))
+        })
+        .collect();
+    let children = with_children
+        .then(|| {
+            entity_ref
+                .get::<Children>()
+                .map(|children| children.to_vec())
+        })
+        .flatten()
+        .unwrap_or_default();
+
+    let mut copy = world.spawn_empty();
+    for (reflect_component, value) in components {
+        reflect_component.insert(&mut copy, &*value, &type_registry);
+    }
+    let copy = copy.id();
+
+    for child in children {
+        if let Some(child_copy) = copy_entity(world, child, true) {
+            world.entity_mut(copy).add_child(child_copy);
+        }
+    }
+
+    Some(copy)
+}
+
+/// Despawns the entities queued by the despawn buttons after the UI pass
+fn despawn_pending_entities(world: &mut World, params: &mut WorldInspectorParams) {
+    let entities = std::mem::take(&mut params.pending_despawns);
+    for &entity in &entities {
+        if params.despawn_recursive {
+            // the entity will already be despawned together with its queued ancestor
+            let mut ancestors = std::iter::successors(world.get::<Parent>(entity), |parent| {
+                world.get::<Parent>(parent.get())
+            });
+            if ancestors.any(|parent| entities.contains(&parent.get())) {
+                continue;
+            }
+        }
+
+        let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+            continue;
+        };
+        if params.despawn_recursive {
+            entity_mut.despawn_recursive();
+        } else {
+            entity_mut.remove_parent().clear_children();
+            entity_mut.despawn();
+        }
+    }
+}
+
+/// Reparents the entity dropped onto another entity's header after the UI pass, unless that would create a cycle
+fn reparent_pending_entity(world: &mut World, params: &mut WorldInspectorParams) {
+    let Some((entity, new_parent)) = params.pending_reparent.take() else {
+        return;
     };
-    self_matches || {
+    if world.get_entity(entity).is_none() {
+        return;
+    }
+
+    match new_parent {
+        Some(new_parent) => {
+            if world.get_entity(new_parent).is_none()
+                || world.get::<Parent>(entity).map(Parent::get) == Some(new_parent)
+            {
+                return;
+            }
+            let mut ancestors = std::iter::successors(Some(new_parent), |&ancestor| {
+                world.get::<Parent>(ancestor).map(Parent::get)
+            });
+            if ancestors.any(|ancestor| ancestor == entity) {
+                return;
+            }
+            world.entity_mut(entity).set_parent(new_parent);
+        }
+        None => {
+            world.entity_mut(entity).remove_parent();
+        }
+    }
+}
+
+/// While an entity is dragged, shows an area below the hierarchy to drop it onto to remove its parent
+fn root_drop_zone_ui(world: &mut World, ui: &mut egui::Ui, params: &mut WorldInspectorParams) {
+    if !egui::DragAndDrop::has_payload_of_type::<Entity>(ui.ctx()) {
+        return;
+    }
+
+    let response = egui::Frame::group(ui.style())
+        .show(ui, |ui| {
+            ui.set_min_width(ui.available_width());
+            ui.weak("Drop here to remove the parent");
+        })
+        .response;
+    if response.dnd_hover_payload::<Entity>().is_some() {
+        ui.painter()
+            .rect_stroke(response.rect, 2.0, ui.visuals().selection.stroke);
+    }
+    if let Some(entity) = response.dnd_release_payload::<Entity>() {
+        params.pending_reparent = Some((*entity, None));
+    }
+    reparent_pending_entity(world, params);
+}
+
+/// The entity name, followed by the dimmed entity id, component count and child count
+/// if [`WorldInspectorParams::show_entity_id`], [`WorldInspectorParams::show_component_count`] and [`WorldInspectorParams::show_child_count`] are set
+fn entity_header_text(
+    ui: &egui::Ui,
+    world: &mut World,
+    entity: Entity,
+    type_registry: &TypeRegistry,
+    params: &mut WorldInspectorParams,
+) -> egui::WidgetText {
+    let components = components_of_entity_cached(&mut world.into(), entity, params);
+    let component_count = params
+        .show_component_count
+        .then(|| components.as_ref().map_or(0, |components| components.len()));
+    let undisplayable_count = components.map_or(0, |components| {
+        components
+            .iter()
+            .filter(|&&(_, _, type_id, size)| !is_displayable(type_id, size, type_registry))
+            .count()
+    });
+
+    let child_count = params
+        .show_child_count
+        .then(|| {
+            world
+                .get::<Children>(entity)
+                .map_or(0, |children| children.len())
+        })
+        .filter(|&count| count > 0);
+
+    let name = params.entity_name_without_id(world, entity);
+    if !params.show_entity_id
+        && component_count.is_none()
+        && child_count.is_none()
+        && undisplayable_count == 0
+    {
+        return name.into_owned().into();
+    }
+
+    let mut suffix = String::new();
+    if params.show_entity_id {
+        suffix.push_str(&format!(" {entity:?}"));
+    }
+    if let Some(count) = component_count {
+        suffix.push_str(&format!(" ({count})"));
+    }
+    match child_count {
+        Some(1) => suffix.push_str(" [1 child]"),
+        Some(count) => suffix.push_str(&format!(" [{count} children]")),
+        None => {}
+    }
+
+    let mut job = egui::text::LayoutJob::default();
+    egui::RichText::new(name).append_to(
+        &mut job,
+        ui.style(),
+        egui::FontSelection::Default,
+        egui::Align::Center,
+    );
+    egui::RichText::new(suffix).weak().append_to(
+        &mut job,
+        ui.style(),
+        egui::FontSelection::Default,
+        egui::Align::Center,
+    );
+    if undisplayable_count > 0 {
+        egui::RichText::new(format!(" ⚠ {undisplayable_count}"))
+            .color(ui.visuals().warn_fg_color)
+            .append_to(
+                &mut job,
+                ui.style(),
+                egui::FontSelection::Default,
+                egui::Align::Center,
+            );
+    }
+    job.into()
+}
+
+/// Whether a component can be displayed, i.e. it is zero-sized or registered with [`ReflectFromPtr`]
+fn is_displayable(type_id: Option<TypeId>, size: usize, type_registry: &TypeRegistry) -> bool {
+    type_id.is_some_and(|type_id| {
+        size == 0
+            || type_registry
+                .get_type_data::<ReflectFromPtr>(type_id)
+                .is_some()
+    })
+}
+
+fn has_matching_component(
+    world: &mut World,
+    entity: Entity,
+    type_registry: &TypeRegistry,
+    params: &mut WorldInspectorParams,
+) -> bool {
+    components_of_entity_cached(&mut world.into(), entity, params).is_some_and(|components| {
+        components.iter().any(|&(ref name, _, type_id, _)| {
+            params.component_matches(name) && !params.is_component_ignored(type_id)
+        })
+    }) || params.match_field_names
+        && has_component_with_matching_field(world, entity, type_registry, params)
+}
+
+/// Whether the entity has a reflected component with a field matching the component filter, see [`WorldInspectorParams::match_field_names`]
+fn has_component_with_matching_field(
+    world: &World,
+    entity: Entity,
+    type_registry: &TypeRegistry,
+    params: &WorldInspectorParams,
+) -> bool {
+    let Some(entity_ref) = world.get_entity(entity) else {
+        return false;
+    };
+    let archetype = &world.archetypes()[entity_ref.location().archetype_id];
+    archetype.components().any(|component_id| {
+        let Some(type_id) = world
+            .components()
+            .get_info(component_id)
+            .and_then(|info| info.type_id())
+        else {
+            return false;
+        };
+        !params.is_component_ignored(Some(type_id))
+            && type_registry
+                .get_type_data::<ReflectComponent>(type_id)
+                .and_then(|reflect_component| reflect_component.reflect(entity_ref))
+                .is_some_and(|value| params.fields_match(value))
+    })
+}
+
+fn entity_satisfies_filter(
+    world: &mut World,
+    entity: Entity,
+    filter: &Filter,
+    type_registry: &TypeRegistry,
+    params: &mut WorldInspectorParams,
+) -> bool {
+    let name_matches = filter.word.is_empty() || {
+        let name = params.entity_name(world, entity);
+        if filter.is_fuzzy {
+            let matcher = SkimMatcherV2::default();
+            matcher.fuzzy_match(name.as_str(), &filter.word).is_some()
+        } else {
+            name.to_lowercase().contains(&filter.word)
+        }
+    };
+    name_matches
+        && (!filter.by_component || has_matching_component(world, entity, type_registry, params))
+        && (!filter.hide_empty || has_displayed_component(world, entity, params))
+        && (!filter.only_unnamed || world.get::<Name>(entity).is_none())
+}
+
+/// Whether the entity has a component which isn't ignored
+fn has_displayed_component(
+    world: &mut World,
+    entity: Entity,
+    params: &mut WorldInspectorParams,
+) -> bool {
+    components_of_entity_cached(&mut world.into(), entity, params).is_some_and(|components| {
+        components
+            .iter()
+            .any(|&(_, _, type_id, _)| !params.is_component_ignored(type_id))
+    })
+}
+
+fn self_or_children_satisfy_filter(
+    world: &mut World,
+    entity: Entity,
+    filter: &Filter,
+    type_registry: &TypeRegistry,
+    params: &mut WorldInspectorParams,
+) -> bool {
+    entity_satisfies_filter(world, entity, filter, type_registry, params) || {
         world
             .query::<&Children>()
             .get(world, entity)
             .map(|children| children.to_vec())
             .is_ok_and(|children| {
-                children
-                    .iter()
-                    .any(|child| self_or_children_satisfy_filter(world, *child, filter, is_fuzzy))
+                children.iter().any(|child| {
+                    self_or_children_satisfy_filter(world, *child, filter, type_registry, params)
+                })
             })
     }
 }
 
 /// Display the given entity with all its components and children
 pub fn ui_for_entity_with_children(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
+    WorldInspectorParams::ui_scope(world, ui, |world, ui, params| {
+        ui_for_entity_with_children_scoped(world, entity, ui, params);
+    });
+}
+
+fn ui_for_entity_with_children_scoped(
+    world: &mut World,
+    entity: Entity,
+    ui: &mut egui::Ui,
+    params: &mut WorldInspectorParams,
+) {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
     let type_registry = type_registry.read();
 
-    let entity_name = guess_entity_name(world, entity);
+    params.keyboard_navigation_ui(ui);
+    InspectorSelection::reveal_changed(world, params);
+    let entity_name = params.entity_name(world, entity);
     ui.label(entity_name);
 
     ui_for_entity_with_children_inner(
@@ -394,7 +1626,11 @@ pub fn ui_for_entity_with_children(world: &mut World, entity: Entity, ui: &mut e
         egui::Id::new(entity),
         &type_registry,
         &Filter::empty(),
-    )
+        params,
+    );
+    despawn_pending_entities(world, params);
+    reparent_pending_entity(world, params);
+    InspectorSelection::update(world, ui.ctx().frame_nr(), params);
 }
 
 fn ui_for_entity_with_children_inner(
@@ -404,6 +1640,7 @@ fn ui_for_entity_with_children_inner(
     id: egui::Id,
     type_registry: &TypeRegistry,
     filter: &Filter,
+    params: &mut WorldInspectorParams,
 ) {
     let mut queue = CommandQueue::default();
     ui_for_entity_components(
@@ -413,33 +1650,41 @@ fn ui_for_entity_with_children_inner(
         ui,
         id,
         type_registry,
+        params,
     );
+    entity_actions_ui(ui, world, entity, id, type_registry, &mut queue, params);
 
     let children = world
         .get::<Children>(entity)
         .map(|children| children.iter().copied().collect::<Vec<_>>());
     if let Some(mut children) = children {
         if !children.is_empty() {
-            filter.filter_entities(world, &mut children);
+            filter.filter_entities(world, &mut children, type_registry, params);
             ui.label("Children");
             for &child in children.iter() {
                 let id = id.with(child);
 
-                let child_entity_name = guess_entity_name(world, child);
-                egui::CollapsingHeader::new(&child_entity_name)
-                    .id_source(id)
-                    .show(ui, |ui| {
-                        ui.label(&child_entity_name);
-
-                        ui_for_entity_with_children_inner(
-                            world,
-                            child,
-                            ui,
-                            id,
-                            type_registry,
-                            filter,
-                        );
-                    });
+                let child_entity_name = params.entity_name(world, child);
+                let header_text = entity_header_text(ui, world, child, type_registry, params);
+                let header = navigable_entity_header(
+                    params.entity_header(egui::CollapsingHeader::new(header_text).id_source(id)),
+                    child,
+                    params,
+                );
+                let response = header.show(ui, |ui| {
+                    ui.label(&child_entity_name);
+
+                    ui_for_entity_with_children_inner(
+                        world,
+                        child,
+                        ui,
+                        id,
+                        type_registry,
+                        filter,
+                        params,
+                    );
+                });
+                entity_header_response(ui, child, &response.header_response, params);
             }
         }
     }
@@ -449,10 +1694,21 @@ fn ui_for_entity_with_children_inner(
 
 /// Display the components of the given entity
 pub fn ui_for_entity(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
+    WorldInspectorParams::ui_scope(world, ui, |world, ui, params| {
+        ui_for_entity_scoped(world, entity, ui, params);
+    });
+}
+
+fn ui_for_entity_scoped(
+    world: &mut World,
+    entity: Entity,
+    ui: &mut egui::Ui,
+    params: &mut WorldInspectorParams,
+) {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
     let type_registry = type_registry.read();
 
-    let entity_name = guess_entity_name(world, entity);
+    let entity_name = params.entity_name(world, entity);
     ui.label(entity_name);
 
     let mut queue = CommandQueue::default();
@@ -463,6 +1719,7 @@ pub fn ui_for_entity(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
         ui,
         egui::Id::new(entity),
         &type_registry,
+        params,
     );
     queue.apply(world);
 }
@@ -475,35 +1732,106 @@ pub(crate) fn ui_for_entity_components(
     ui: &mut egui::Ui,
     id: egui::Id,
     type_registry: &TypeRegistry,
+    params: &mut WorldInspectorParams,
 ) {
-    let Some(components) = components_of_entity(world, entity) else {
+    let Some(components) = components_of_entity_cached(world, entity, params) else {
         errors::entity_does_not_exist(ui, entity);
         return;
     };
 
-    for (name, component_id, component_type_id, size) in components {
+    let field_filter = params.field_filter();
+    for &(ref name, component_id, component_type_id, size) in components.iter() {
+        // without a matching name, reflected components can still match by their field names
+        let name_matches = params.component_matches(name);
+        let may_match_fields = field_filter.is_some() && component_type_id.is_some() && size > 0;
+        if (!name_matches && !may_match_fields) || params.is_component_ignored(component_type_id) {
+            continue;
+        }
+
         let id = id.with(component_id);
+        let type_path = world
+            .world()
+            .components()
+            .get_info(component_id)
+            .map_or(name.as_str(), |info| info.name());
 
-        let header = egui::CollapsingHeader::new(&name).id_source(id);
+        let header = egui::CollapsingHeader::new(name).id_source(id);
+
+        // change detection is only available for reflected components with data
+        if params.show_only_changed && (component_type_id.is_none() || size == 0) {
+            continue;
+        }
 
         let Some(component_type_id) = component_type_id else {
-            header.show(ui, |ui| errors::no_type_id(ui, &name));
+            let response = header.show(ui, |ui| errors::no_type_id(ui, name));
+            component_header_response(
+                response.header_response,
+                type_path,
+                None,
+                entity,
+                None,
+                queue.as_deref_mut(),
+                type_registry,
+                params,
+            );
             continue;
         };
 
         if size == 0 {
-            header.show(ui, |_| {});
+            let response = header.show(ui, |_| {});
+            component_header_response(
+                response.header_response,
+                type_path,
+                None,
+                entity,
+                Some(component_type_id),
+                queue.as_deref_mut(),
+                type_registry,
+                params,
+            );
+            continue;
+        }
+
+        if let Some(snapshot) = &params.snapshot {
+            let Some(value) = snapshot.get(&(entity, component_type_id)) else {
+                continue;
+            };
+            if !name_matches && !params.fields_match(&**value) {
+                continue;
+            }
+            let response = header.show(ui, |ui| {
+                params.reset_style(ui);
+                ui.push_id((entity, component_id), |ui| {
+                    let mut cx = Context {
+                        number_defaults: params.number_defaults(),
+                        highlight_fields: field_filter.as_deref(),
+                        world_inspector_params: Some(params),
+                        ..Default::default()
+                    };
+                    let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+                    env.ui_for_reflect_readonly_with_options(
+                        &**value,
+                        ui,
+                        id.with(component_id),
+                        &(),
+                    );
+                });
+            });
+            component_header_response(
+                response.header_response,
+                type_path,
+                None,
+                entity,
+                Some(component_type_id),
+                queue.as_deref_mut(),
+                type_registry,
+                params,
+            );
             continue;
         }
 
         // create a context with access to the world except for the currently viewed component
         let (mut component_view, world) = world.split_off_component((entity, component_type_id));
-        let mut cx = Context {
-            world: Some(world),
-            #[allow(clippy::needless_option_as_deref)]
-            queue: queue.as_deref_mut(),
-        };
-
         let (value, is_changed, set_changed) = match component_view.get_entity_component_reflect(
             entity,
             component_type_id,
@@ -511,28 +1839,263 @@ pub(crate) fn ui_for_entity_components(
         ) {
             Ok(value) => value,
             Err(e) => {
-                header.show(ui, |ui| errors::show_error(e, ui, &name));
+                let response = header.show(ui, |ui| errors::show_error(e, ui, name));
+                component_header_response(
+                    response.header_response,
+                    type_path,
+                    None,
+                    entity,
+                    Some(component_type_id),
+                    queue.as_deref_mut(),
+                    type_registry,
+                    params,
+                );
                 continue;
             }
         };
 
+        if params.show_only_changed && !is_changed {
+            continue;
+        }
+
+        let frame = ui.ctx().frame_nr();
+        let time = ui.input(|input| input.time);
+        if is_changed {
+            params
+                .last_changed
+                .insert((entity, component_id), (frame, time));
+        }
+        let last_changed = params.last_changed.get(&(entity, component_id));
+        let frames_since_change = last_changed.map(|&(changed, _)| frame - changed);
+        let seconds_since_change = last_changed.map(|&(_, changed)| time - changed);
+
+        let header = match params
+            .component_summaries
+            .then(|| component_summary(ui, id, name, value))
+            .flatten()
+        {
+            Some(text) => egui::CollapsingHeader::new(text).id_source(id),
+            None => header,
+        };
+
+        // expand the components which only match by their field names when the component filter is edited
+        let header = if name_matches {
+            header
+        } else if params.fields_match(value) {
+            header.open(params.component_filter_changed.then_some(true))
+        } else {
+            continue;
+        };
+
         if is_changed {
             #[cfg(feature = "highlight_changes")]
             set_highlight_style(ui);
         }
+        if let Some(seconds) = seconds_since_change.filter(|_| params.highlight_changes) {
+            set_change_fade_style(ui, seconds);
+        }
+
+        let mut cx = Context {
+            world: Some(world),
+            #[allow(clippy::needless_option_as_deref)]
+            queue: queue.as_deref_mut(),
+            number_defaults: params.number_defaults(),
+            wrap_fields: params.wrap_fields,
+            debug_fallback: params.debug_fallback,
+            reset_buttons: params.reset_buttons,
+            highlight_fields: field_filter.as_deref(),
+            world_inspector_params: Some(params),
+        };
+
+        if params.inline_single_field && has_single_primitive_field(value) {
+            let response = ui
+                .horizontal(|ui| {
+                    let response = ui.add(egui::Label::new(name).sense(egui::Sense::click()));
+                    params.reset_style(ui);
+                    ui.push_id((entity, component_id), |ui| {
+                        let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+                        let id = id.with(component_id);
+                        if params.read_only {
+                            env.ui_for_reflect_readonly_with_options(value, ui, id, &());
+                        } else if env.ui_for_reflect_with_options(value, ui, id, &()) {
+                            set_changed();
+                        }
+                    });
+                    response
+                })
+                .inner;
+            params.reset_style(ui);
+            component_header_response(
+                response,
+                type_path,
+                frames_since_change,
+                entity,
+                Some(component_type_id),
+                queue.as_deref_mut(),
+                type_registry,
+                params,
+            );
+            continue;
+        }
 
-        header.show(ui, |ui| {
-            ui.reset_style();
+        let response = header.show(ui, |ui| {
+            params.reset_style(ui);
 
-            let inspector_changed = InspectorUi::for_bevy(type_registry, &mut cx)
-                .ui_for_reflect_with_options(value, ui, id.with(component_id), &());
+            // scope the automatically generated widget ids, so that they can't collide with those of other components
+            ui.push_id((entity, component_id), |ui| {
+                if params.read_only {
+                    let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+                    env.ui_for_reflect_readonly_with_options(value, ui, id.with(component_id), &());
+                    return;
+                }
 
-            if inspector_changed {
-                set_changed();
-            }
+                let mut changed = false;
+                if params.reset_buttons {
+                    if let Some(reflect_default) =
+                        type_registry.get_type_data::<ReflectDefault>(component_type_id)
+                    {
+                        let button =
+                            egui::Button::new("↺ Reset component")
+                                .small()
+                                .sense(egui::Sense {
+                                    focusable: false,
+                                    ..egui::Sense::click()
+                                });
+                        if ui.add(button).clicked() {
+                            value.apply(&*reflect_default.default());
+                            changed = true;
+                        }
+                    }
+                }
+                let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+                changed |= env.ui_for_reflect_with_options(value, ui, id.with(component_id), &());
+                if changed {
+                    set_changed();
+                }
+            });
         });
-        ui.reset_style();
+        params.reset_style(ui);
+        component_header_response(
+            response.header_response,
+            type_path,
+            frames_since_change,
+            entity,
+            Some(component_type_id),
+            queue.as_deref_mut(),
+            type_registry,
+            params,
+        );
+    }
+}
+
+/// The component name followed by the dimmed, truncated [`Debug`](std::fmt::Debug) output of its value, like `Health 100.0`,
+/// if its header is collapsed. See [`WorldInspectorParams::component_summaries`].
+fn component_summary(
+    ui: &egui::Ui,
+    id: egui::Id,
+    name: &str,
+    value: &dyn Reflect,
+) -> Option<egui::WidgetText> {
+    const MAX_LEN: usize = 48;
+
+    let is_open =
+        egui::collapsing_header::CollapsingState::load(ui.ctx(), ui.make_persistent_id(id))
+            .is_some_and(|state| state.is_open());
+    if is_open {
+        return None;
     }
+
+    let mut summary = format!("{value:?}");
+    crate::utils::truncate(&mut summary, MAX_LEN);
+
+    let mut job = egui::text::LayoutJob::default();
+    egui::RichText::new(name).append_to(
+        &mut job,
+        ui.style(),
+        egui::FontSelection::Default,
+        egui::Align::Center,
+    );
+    egui::RichText::new(format!(" {summary}")).weak().append_to(
+        &mut job,
+        ui.style(),
+        egui::FontSelection::Default,
+        egui::Align::Center,
+    );
+    Some(job.into())
+}
+
+/// Whether the value is a struct or tuple struct with one field, which is a primitive value like `Health(f32)`
+fn has_single_primitive_field(value: &dyn Reflect) -> bool {
+    let field = match value.reflect_ref() {
+        ReflectRef::Struct(value) if value.field_len() == 1 => value.field_at(0),
+        ReflectRef::TupleStruct(value) if value.field_len() == 1 => value.field(0),
+        _ => None,
+    };
+    field.is_some_and(|field| matches!(field.reflect_ref(), ReflectRef::Value(_)))
+}
+
+/// Shows the full type path of a component when hovering its header, and a right-click menu with actions for the component
+#[allow(clippy::too_many_arguments)]
+fn component_header_response(
+    response: egui::Response,
+    type_path: &str,
+    frames_since_change: Option<u64>,
+    entity: Entity,
+    type_id: Option<TypeId>,
+    queue: Option<&mut CommandQueue>,
+    type_registry: &TypeRegistry,
+    params: &mut WorldInspectorParams,
+) {
+    let hover_text = match frames_since_change {
+        Some(0) => format!("{type_path}\nChanged this frame"),
+        Some(frames) => format!("{type_path}\nLast changed {frames} frames ago"),
+        None => type_path.to_owned(),
+    };
+    response.on_hover_text(hover_text).context_menu(|ui| {
+        if ui.button("Copy type path").clicked() {
+            ui.output_mut(|output| output.copied_text = type_path.to_owned());
+            ui.close_menu();
+        }
+
+        if let Some(queue) = queue.filter(|_| !params.read_only) {
+            let can_reset = type_id
+                .and_then(|type_id| type_registry.get(type_id))
+                .is_some_and(|registration| {
+                    registration.data::<ReflectComponent>().is_some()
+                        && (registration.data::<ReflectDefault>().is_some()
+                            || registration.data::<ReflectFromWorld>().is_some())
+                });
+            if let Some(type_id) = type_id.filter(|_| can_reset) {
+                if ui.button("Reset to default").clicked() {
+                    queue.push(move |world: &mut World| {
+                        insert_default_component(world, entity, type_id)
+                    });
+                    ui.close_menu();
+                }
+            }
+            let reflect_component = type_id
+                .and_then(|type_id| type_registry.get_type_data::<ReflectComponent>(type_id));
+            if let Some(reflect_component) = reflect_component {
+                if ui.button("Remove component").clicked() {
+                    let reflect_component = reflect_component.clone();
+                    queue.push(move |world: &mut World| {
+                        if let Some(mut entity) = world.get_entity_mut(entity) {
+                            reflect_component.remove(&mut entity);
+                        }
+                    });
+                    ui.close_menu();
+                }
+            }
+        }
+
+        if let Some(type_id) = type_id {
+            if ui.button("Ignore this type").clicked() {
+                params.ignore_components.insert(type_id);
+                params.show_ignored_components = false;
+                ui.close_menu();
+            }
+        }
+    });
 }
 
 #[cfg(feature = "highlight_changes")]
@@ -559,10 +2122,60 @@ fn set_highlight_style(ui: &mut egui::Ui) {
     };
 }
 
+/// Fills the component header with a highlight color which fades out over the half second after a change.
+/// See [`WorldInspectorParams::highlight_changes`].
+fn set_change_fade_style(ui: &mut egui::Ui, seconds_since_change: f64) {
+    const FADE_SECONDS: f64 = 0.5;
+
+    if seconds_since_change >= FADE_SECONDS {
+        return;
+    }
+    // keep repainting until the highlight is gone, even if nothing else changes
+    ui.ctx().request_repaint();
+
+    let strength = (1.0 - seconds_since_change / FADE_SECONDS) as f32;
+    let visuals = &mut ui.style_mut().visuals;
+    visuals.collapsing_header_frame = true;
+    visuals.widgets.inactive.weak_bg_fill = egui::Color32::GOLD.gamma_multiply(0.5 * strength);
+}
+
+/// Like [`components_of_entity`], but reuses the component list of the entity's archetype from previous frames.
+///
+/// Archetypes are never removed or changed, so the lists only have to be invalidated when the sorting changes.
+fn components_of_entity_cached(
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+    params: &mut WorldInspectorParams,
+) -> Option<Arc<[ComponentEntry]>> {
+    let archetype = world.world().get_entity(entity)?.archetype().id();
+
+    let cache = &mut params.component_cache;
+    if cache.order != params.component_order {
+        cache.order = params.component_order;
+        cache.by_archetype.clear();
+    }
+    if let Some(components) = cache.by_archetype.get(&archetype) {
+        return Some(Arc::clone(components));
+    }
+
+    let components: Arc<[ComponentEntry]> =
+        components_of_entity(world, entity, params.component_order, &params.display_names)?.into();
+    cache
+        .by_archetype
+        .insert(archetype, Arc::clone(&components));
+    Some(components)
+}
+
+/// Lists the components of the entity as `(pretty name, component id, type id, size)`.
+///
+/// The pretty name is taken from `display_names` if the type is in there.
+/// The components are sorted according to `order`, see [`ComponentOrder`].
 fn components_of_entity(
     world: &mut RestrictedWorldView<'_>,
     entity: Entity,
-) -> Option<Vec<(String, ComponentId, Option<TypeId>, usize)>> {
+    order: ComponentOrder,
+    display_names: &HashMap<TypeId, String>,
+) -> Option<Vec<ComponentEntry>> {
     let entity_ref = world.world().get_entity(entity)?;
 
     let archetype = entity_ref.archetype();
@@ -570,12 +2183,40 @@ fn components_of_entity(
         .components()
         .map(|component_id| {
             let info = world.world().components().get_info(component_id).unwrap();
-            let name = pretty_type_name::pretty_type_name_str(info.name());
+            let name = match info
+                .type_id()
+                .and_then(|type_id| display_names.get(&type_id))
+            {
+                Some(name) => name.clone(),
+                None => crate::utils::pretty_type_name::pretty_type_name_str(info.name()),
+            };
 
-            (name, component_id, info.type_id(), info.layout().size())
+            (
+                name,
+                info.name(),
+                component_id,
+                info.type_id(),
+                info.layout().size(),
+            )
         })
         .collect();
-    components.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
+    match order {
+        ComponentOrder::Archetype => {}
+        ComponentOrder::Alphabetical => {
+            components.sort_by(|(name_a, full_name_a, ..), (name_b, full_name_b, ..)| {
+                name_a
+                    .cmp(name_b)
+                    .then_with(|| full_name_a.cmp(full_name_b))
+            });
+        }
+        ComponentOrder::RegistryOrder => {
+            components.sort_by_key(|&(_, _, component_id, ..)| component_id);
+        }
+    }
+    let components = components
+        .into_iter()
+        .map(|(name, _, component_id, type_id, size)| (name, component_id, type_id, size))
+        .collect();
     Some(components)
 }
 
@@ -592,7 +2233,12 @@ pub fn ui_for_entities_shared_components(
         return;
     };
 
-    let Some(mut components) = components_of_entity(&mut world.into(), first) else {
+    let Some(mut components) = components_of_entity(
+        &mut world.into(),
+        first,
+        ComponentOrder::Alphabetical,
+        &HashMap::default(),
+    ) else {
         return errors::entity_does_not_exist(ui, first);
     };
 
@@ -600,7 +2246,7 @@ pub fn ui_for_entities_shared_components(
         components.retain(|(_, id, _, _)| {
             world
                 .get_entity(entity)
-                .map_or(true, |entity| entity.contains_id(*id))
+                .is_none_or(|entity| entity.contains_id(*id))
         })
     }
 
@@ -609,6 +2255,7 @@ pub fn ui_for_entities_shared_components(
     let mut cx = Context {
         world: Some(resources_view),
         queue: Some(&mut queue),
+        ..Default::default()
     };
     let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
 
@@ -685,16 +2332,36 @@ pub mod by_type_id {
 
     use super::{
         errors::{self, name_of_type},
-        handle_name,
+        handle_name, WorldInspectorParams,
     };
 
-    /// Display the resource with the given [`TypeId`]
+    /// Display the resource with the given [`TypeId`], using the [`WorldInspectorParams`] resource if it exists
     pub fn ui_for_resource(
         world: &mut World,
         resource_type_id: TypeId,
         ui: &mut egui::Ui,
         name_of_type: &str,
         type_registry: &TypeRegistry,
+    ) {
+        WorldInspectorParams::scope(world, |world, params| {
+            ui_for_resource_inner(
+                world,
+                resource_type_id,
+                ui,
+                name_of_type,
+                type_registry,
+                params,
+            );
+        });
+    }
+
+    pub(crate) fn ui_for_resource_inner(
+        world: &mut World,
+        resource_type_id: TypeId,
+        ui: &mut egui::Ui,
+        name_of_type: &str,
+        type_registry: &TypeRegistry,
+        params: &WorldInspectorParams,
     ) {
         let mut queue = CommandQueue::default();
 
@@ -705,6 +2372,12 @@ pub mod by_type_id {
             let mut cx = Context {
                 world: Some(world_view),
                 queue: Some(&mut queue),
+                number_defaults: params.number_defaults(),
+                wrap_fields: params.wrap_fields,
+                debug_fallback: params.debug_fallback,
+                reset_buttons: params.reset_buttons,
+                highlight_fields: None,
+                world_inspector_params: Some(params),
             };
             let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
 
@@ -715,8 +2388,9 @@ pub mod by_type_id {
                 Err(err) => return errors::show_error(err, ui, name_of_type),
             };
 
-            let changed = env.ui_for_reflect(resource, ui);
-            if changed {
+            if params.read_only || params.read_only_resources.contains(&resource_type_id) {
+                env.ui_for_reflect_readonly(resource, ui);
+            } else if env.ui_for_reflect(resource, ui) {
                 set_changed();
             }
         }
@@ -724,12 +2398,72 @@ pub mod by_type_id {
         queue.apply(world);
     }
 
-    /// Display all assets of the given asset [`TypeId`]
+    /// Display the component with the given [`TypeId`] of an entity, for embedding single components in custom layouts.
+    ///
+    /// Returns whether the component was changed.
+    pub fn ui_for_component(
+        world: &mut World,
+        entity: Entity,
+        component_type_id: TypeId,
+        ui: &mut egui::Ui,
+        type_registry: &TypeRegistry,
+    ) -> bool {
+        let mut queue = CommandQueue::default();
+
+        let changed = {
+            // create a context with access to the world except for the current component
+            let mut world_view = RestrictedWorldView::new(world);
+            let (mut component_view, world_view) =
+                world_view.split_off_component((entity, component_type_id));
+            let mut cx = Context {
+                world: Some(world_view),
+                queue: Some(&mut queue),
+                ..Default::default()
+            };
+            let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+
+            let (value, _, set_changed) = match component_view.get_entity_component_reflect(
+                entity,
+                component_type_id,
+                type_registry,
+            ) {
+                Ok(value) => value,
+                Err(err) => {
+                    let name = name_of_type(component_type_id, type_registry);
+                    errors::show_error(err, ui, &name);
+                    return false;
+                }
+            };
+
+            let changed = env.ui_for_reflect(value, ui);
+            if changed {
+                set_changed();
+            }
+            changed
+        };
+
+        queue.apply(world);
+        changed
+    }
+
+    /// Display all assets of the given asset [`TypeId`], using the [`WorldInspectorParams`] resource if it exists
     pub fn ui_for_assets(
         world: &mut World,
         asset_type_id: TypeId,
         ui: &mut egui::Ui,
         type_registry: &TypeRegistry,
+    ) {
+        WorldInspectorParams::scope(world, |world, params| {
+            ui_for_assets_inner(world, asset_type_id, ui, type_registry, params);
+        });
+    }
+
+    pub(crate) fn ui_for_assets_inner(
+        world: &mut World,
+        asset_type_id: TypeId,
+        ui: &mut egui::Ui,
+        type_registry: &TypeRegistry,
+        params: &WorldInspectorParams,
     ) {
         let asset_server = world.get_resource::<AssetServer>().cloned();
 
@@ -765,6 +2499,12 @@ pub mod by_type_id {
         let mut cx = Context {
             world: Some(world_view),
             queue: Some(&mut queue),
+            number_defaults: params.number_defaults(),
+            wrap_fields: params.wrap_fields,
+            debug_fallback: params.debug_fallback,
+            reset_buttons: params.reset_buttons,
+            highlight_fields: None,
+            world_inspector_params: Some(params),
         };
 
         for handle_id in ids {
@@ -775,7 +2515,11 @@ pub mod by_type_id {
                 .id_source(id)
                 .show(ui, |ui| {
                     let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
-                    env.ui_for_reflect_with_options(&mut *handle, ui, id, &());
+                    if params.read_only {
+                        env.ui_for_reflect_readonly_with_options(&*handle, ui, id, &());
+                    } else {
+                        env.ui_for_reflect_with_options(&mut *handle, ui, id, &());
+                    }
                 });
         }
 
@@ -825,6 +2569,7 @@ pub mod by_type_id {
         let mut cx = Context {
             world: Some(world_view),
             queue: Some(&mut queue),
+            ..Default::default()
         };
 
         let id = egui::Id::new(handle);
@@ -877,12 +2622,18 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
 pub mod short_circuit {
     use std::any::{Any, TypeId};
 
-    use bevy_asset::ReflectAsset;
+    use bevy_asset::{AssetServer, ReflectAsset, UntypedAssetId};
     use bevy_reflect::Reflect;
 
-    use crate::reflect_inspector::{Context, InspectorUi};
+    use crate::{
+        reflect_inspector::{Context, InspectorUi},
+        restricted_world_view::RestrictedWorldView,
+    };
 
-    use super::errors::{self, name_of_type};
+    use super::{
+        errors::{self, name_of_type},
+        handle_name,
+    };
 
     pub fn short_circuit(
         env: &mut InspectorUi,
@@ -914,14 +2665,21 @@ pub mod short_circuit {
             let Context {
                 world: Some(world),
                 queue,
+                number_defaults,
+                wrap_fields,
+                debug_fallback,
+                reset_buttons,
+                highlight_fields,
+                world_inspector_params,
             } = &mut env.context
             else {
                 errors::no_world_in_context(ui, value.reflect_short_type_path());
                 return Some(false);
             };
 
-            let (assets_view, world) =
+            let (assets_view, mut world) =
                 world.split_off_resource(reflect_asset.assets_resource_type_id());
+            handle_name_ui(ui, &mut world, handle_id);
 
             let asset_value = {
                 assert!(
@@ -944,6 +2702,12 @@ pub mod short_circuit {
                 context: &mut Context {
                     world: Some(world),
                     queue: queue.as_deref_mut(),
+                    number_defaults: *number_defaults,
+                    wrap_fields: *wrap_fields,
+                    debug_fallback: *debug_fallback,
+                    reset_buttons: *reset_buttons,
+                    highlight_fields: *highlight_fields,
+                    world_inspector_params: *world_inspector_params,
                 },
                 short_circuit: env.short_circuit,
                 short_circuit_readonly: env.short_circuit_readonly,
@@ -960,6 +2724,19 @@ pub mod short_circuit {
         None
     }
 
+    /// Shows the asset path of the handle, or its id if it has no path
+    fn handle_name_ui(
+        ui: &mut egui::Ui,
+        world: &mut RestrictedWorldView<'_>,
+        handle_id: UntypedAssetId,
+    ) {
+        let asset_server = world
+            .get_resource_mut::<AssetServer>()
+            .ok()
+            .map(|asset_server| AssetServer::clone(&asset_server));
+        ui.label(egui::RichText::new(handle_name(handle_id, asset_server.as_ref())).weak());
+    }
+
     pub fn short_circuit_many(
         env: &mut InspectorUi,
         type_id: TypeId,
@@ -989,6 +2766,12 @@ pub mod short_circuit {
             let Context {
                 world: Some(world),
                 queue,
+                number_defaults,
+                wrap_fields,
+                debug_fallback,
+                reset_buttons,
+                highlight_fields,
+                world_inspector_params,
             } = &mut env.context
             else {
                 errors::no_world_in_context(ui, type_name);
@@ -1036,6 +2819,12 @@ pub mod short_circuit {
                 context: &mut Context {
                     world: Some(world),
                     queue: queue.as_deref_mut(),
+                    number_defaults: *number_defaults,
+                    wrap_fields: *wrap_fields,
+                    debug_fallback: *debug_fallback,
+                    reset_buttons: *reset_buttons,
+                    highlight_fields: *highlight_fields,
+                    world_inspector_params: *world_inspector_params,
                 },
                 short_circuit: env.short_circuit,
                 short_circuit_readonly: env.short_circuit_readonly,
@@ -1085,14 +2874,21 @@ pub mod short_circuit {
             let Context {
                 world: Some(world),
                 queue,
+                number_defaults,
+                wrap_fields,
+                debug_fallback,
+                reset_buttons,
+                highlight_fields,
+                world_inspector_params,
             } = &mut env.context
             else {
                 errors::no_world_in_context(ui, value.reflect_short_type_path());
                 return Some(());
             };
 
-            let (assets_view, world) =
+            let (assets_view, mut world) =
                 world.split_off_resource(reflect_asset.assets_resource_type_id());
+            handle_name_ui(ui, &mut world, handle_id);
 
             let asset_value = {
                 // SAFETY: the following code only accesses a resources it has access to, `Assets<T>`
@@ -1115,6 +2911,12 @@ pub mod short_circuit {
                 context: &mut Context {
                     world: Some(world),
                     queue: queue.as_deref_mut(),
+                    number_defaults: *number_defaults,
+                    wrap_fields: *wrap_fields,
+                    debug_fallback: *debug_fallback,
+                    reset_buttons: *reset_buttons,
+                    highlight_fields: *highlight_fields,
+                    world_inspector_params: *world_inspector_params,
                 },
                 short_circuit: env.short_circuit,
                 short_circuit_readonly: env.short_circuit_readonly,
@@ -1134,3 +2936,365 @@ pub mod short_circuit {
 }
 
 pub use crate::utils::guess_entity_name::guess_entity_name;
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::prelude::*;
+    use bevy_hierarchy::{BuildWorldChildren, Children, Parent};
+    use bevy_math::{Quat, Vec3};
+    use bevy_reflect::Reflect;
+    use bevy_transform::components::Transform;
+
+    #[derive(Component)]
+    struct NotReflected;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Alpha(f32);
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Beta(f32);
+
+    use super::{
+        despawn_pending_entities, duplicate_entity, entity_path, entity_to_json,
+        reparent_pending_entity, ui_for_entity, ui_for_entity_components, ui_for_world,
+        WorldInspectorParams,
+    };
+
+    fn spawn_parent_with_child(world: &mut World) -> (Entity, Entity) {
+        let child = world.spawn_empty().id();
+        let parent = world.spawn_empty().add_child(child).id();
+        (parent, child)
+    }
+
+    #[test]
+    fn despawn_parent_and_child_recursive() {
+        let mut world = World::new();
+        let (parent, child) = spawn_parent_with_child(&mut world);
+
+        let mut params = WorldInspectorParams {
+            pending_despawns: vec![child, parent],
+            ..Default::default()
+        };
+        despawn_pending_entities(&mut world, &mut params);
+
+        assert!(world.get_entity(parent).is_none());
+        assert!(world.get_entity(child).is_none());
+        assert_eq!(world.entities().len(), 0);
+        assert!(params.pending_despawns.is_empty());
+    }
+
+    #[test]
+    fn despawn_parent_and_child_non_recursive() {
+        let mut world = World::new();
+        let (parent, child) = spawn_parent_with_child(&mut world);
+        let sibling = world.spawn_empty().id();
+        world.entity_mut(parent).add_child(sibling);
+
+        let mut params = WorldInspectorParams {
+            despawn_recursive: false,
+            pending_despawns: vec![parent, child],
+            ..Default::default()
+        };
+        despawn_pending_entities(&mut world, &mut params);
+
+        assert!(world.get_entity(parent).is_none());
+        assert!(world.get_entity(child).is_none());
+        assert!(world.get::<Parent>(sibling).is_none());
+        assert_eq!(world.entities().len(), 1);
+    }
+
+    #[test]
+    fn reparent_onto_descendant_is_rejected() {
+        let mut world = World::new();
+        let (parent, child) = spawn_parent_with_child(&mut world);
+        let grandchild = world.spawn_empty().id();
+        world.entity_mut(child).add_child(grandchild);
+
+        let mut params = WorldInspectorParams {
+            pending_reparent: Some((parent, Some(grandchild))),
+            ..Default::default()
+        };
+        reparent_pending_entity(&mut world, &mut params);
+
+        assert!(world.get::<Parent>(parent).is_none());
+        assert_eq!(world.get::<Parent>(child).map(Parent::get), Some(parent));
+        assert_eq!(
+            world.get::<Parent>(grandchild).map(Parent::get),
+            Some(child)
+        );
+        assert!(params.pending_reparent.is_none());
+    }
+
+    #[test]
+    fn reparent_onto_itself_is_rejected() {
+        let mut world = World::new();
+        let (parent, _) = spawn_parent_with_child(&mut world);
+
+        let mut params = WorldInspectorParams {
+            pending_reparent: Some((parent, Some(parent))),
+            ..Default::default()
+        };
+        reparent_pending_entity(&mut world, &mut params);
+
+        assert!(world.get::<Parent>(parent).is_none());
+    }
+
+    #[test]
+    fn reparent_onto_unrelated_entity() {
+        let mut world = World::new();
+        let (parent, child) = spawn_parent_with_child(&mut world);
+        let other = world.spawn_empty().id();
+
+        let mut params = WorldInspectorParams {
+            pending_reparent: Some((child, Some(other))),
+            ..Default::default()
+        };
+        reparent_pending_entity(&mut world, &mut params);
+
+        assert_eq!(world.get::<Parent>(child).map(Parent::get), Some(other));
+        assert!(world
+            .get::<Children>(parent)
+            .is_none_or(|children| children.is_empty()));
+    }
+
+    #[test]
+    fn entity_path_of_same_named_siblings() {
+        let mut world = World::new();
+        let first = world.spawn(Name::new("Enemy")).id();
+        let boss = world.spawn(Name::new("Boss")).id();
+        let second = world.spawn(Name::new("Enemy")).id();
+        let level = world
+            .spawn(Name::new("Level"))
+            .push_children(&[first, boss, second])
+            .id();
+        let weapon = world.spawn(Name::new("Weapon")).set_parent(second).id();
+
+        assert_eq!(entity_path(&world, level), "Level");
+        assert_eq!(entity_path(&world, first), "Level/Enemy[0]");
+        assert_eq!(entity_path(&world, second), "Level/Enemy[1]");
+        assert_eq!(entity_path(&world, boss), "Level/Boss");
+        assert_eq!(entity_path(&world, weapon), "Level/Enemy[1]/Weapon");
+    }
+
+    #[test]
+    fn entity_path_of_same_named_roots() {
+        let mut world = World::new();
+        let first = world.spawn(Name::new("Level")).id();
+        let second = world.spawn((Name::new("Level"), Transform::default())).id();
+
+        assert_eq!(entity_path(&world, first), "Level[0]");
+        assert_eq!(entity_path(&world, second), "Level[1]");
+    }
+
+    #[test]
+    fn duplicate_entity_with_children() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut type_registry = world.resource::<AppTypeRegistry>().write();
+            type_registry.register::<Name>();
+            type_registry.register::<Transform>();
+        }
+
+        let transform = Transform::from_xyz(1.0, 2.0, 3.0);
+        let child = world.spawn((transform, NotReflected)).id();
+        let entity = world
+            .spawn((Name::new("Enemy"), transform, NotReflected))
+            .add_child(child)
+            .id();
+        let parent = world.spawn_empty().add_child(entity).id();
+
+        let copy = duplicate_entity(&mut world, entity, true).unwrap();
+
+        assert_ne!(copy, entity);
+        assert_eq!(world.get::<Name>(copy).unwrap().as_str(), "Enemy (copy)");
+        assert_eq!(world.get::<Transform>(copy), Some(&transform));
+        assert!(world.get::<NotReflected>(copy).is_none());
+        assert_eq!(world.get::<Parent>(copy).map(Parent::get), Some(parent));
+        assert_eq!(world.get::<Children>(parent).unwrap().len(), 2);
+
+        let child_copies = world.get::<Children>(copy).unwrap();
+        assert_eq!(child_copies.len(), 1);
+        let child_copy = child_copies[0];
+        assert_ne!(child_copy, child);
+        assert_eq!(
+            world.get::<Transform>(child_copy).map(|t| t.translation),
+            Some(Vec3::new(1.0, 2.0, 3.0))
+        );
+        assert!(world.get::<NotReflected>(child_copy).is_none());
+        assert_eq!(world.get::<Children>(entity).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn duplicate_entity_without_children() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Transform>();
+
+        let child = world.spawn_empty().id();
+        let entity = world.spawn(Transform::default()).add_child(child).id();
+
+        let copy = duplicate_entity(&mut world, entity, false).unwrap();
+
+        assert!(world.get::<Name>(copy).is_none());
+        assert!(world.get::<Transform>(copy).is_some());
+        assert!(world.get::<Parent>(copy).is_none());
+        assert!(world.get::<Children>(copy).is_none());
+    }
+
+    #[test]
+    fn entity_to_json_skips_unreflected_components() {
+        let mut world = World::new();
+        let type_registry = AppTypeRegistry::default();
+        {
+            let mut type_registry = type_registry.write();
+            type_registry.register::<Transform>();
+            type_registry.register::<Vec3>();
+            type_registry.register::<Quat>();
+        }
+        let entity = world
+            .spawn((Transform::from_xyz(1.0, 2.0, 3.0), NotReflected))
+            .id();
+
+        let json = entity_to_json(&world, entity, &type_registry.read());
+        let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json["entity"], format!("{entity:?}"));
+        let components = json["components"].as_object().unwrap();
+        assert_eq!(components.len(), 1);
+        let transform = &components[std::any::type_name::<Transform>()];
+        assert_eq!(
+            transform["translation"],
+            serde_json::json!({ "x": 1.0, "y": 2.0, "z": 3.0 })
+        );
+        assert_eq!(
+            transform["scale"],
+            serde_json::json!({ "x": 1.0, "y": 1.0, "z": 1.0 })
+        );
+        assert_eq!(
+            json["skipped"],
+            serde_json::json!([std::any::type_name::<NotReflected>()])
+        );
+    }
+
+    #[test]
+    fn entity_to_json_of_despawned_entity() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        world.despawn(entity);
+
+        let json = entity_to_json(&world, entity, &AppTypeRegistry::default().read());
+        assert!(json.is_empty());
+    }
+
+    /// Runs one egui frame and returns the texts which were drawn
+    fn run_ui(
+        ctx: &egui::Context,
+        world: &mut World,
+        mut f: impl FnMut(&mut World, &mut egui::Ui),
+    ) -> Vec<String> {
+        fn collect_texts(shape: &egui::Shape, texts: &mut Vec<String>) {
+            match shape {
+                egui::Shape::Text(text) => texts.push(text.galley.job.text.clone()),
+                egui::Shape::Vec(shapes) => {
+                    for shape in shapes {
+                        collect_texts(shape, texts);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let output = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| f(world, ui));
+        });
+        let mut texts = Vec::new();
+        for clipped in &output.shapes {
+            collect_texts(&clipped.shape, &mut texts);
+        }
+        texts
+    }
+
+    #[test]
+    fn ui_for_despawned_and_moved_entities() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut type_registry = world.resource::<AppTypeRegistry>().write();
+            type_registry.register::<Name>();
+            type_registry.register::<Transform>();
+        }
+        let moved = world.spawn((Name::new("Moved"), Transform::default())).id();
+        let despawned = world.spawn(Transform::default()).id();
+        // the same context, so that the UI state kept in egui memory carries over to the next frame
+        let ctx = egui::Context::default();
+
+        let texts = run_ui(&ctx, &mut world, |world, ui| {
+            ui_for_entity(world, moved, ui);
+            ui_for_entity(world, despawned, ui);
+            ui_for_world(world, ui);
+        });
+        let does_not_exist = format!("Entity {despawned:?} does not exist.");
+        assert_eq!(texts.iter().filter(|text| *text == "Transform").count(), 2);
+        assert!(!texts.contains(&does_not_exist));
+
+        world.despawn(despawned);
+        world
+            .entity_mut(moved)
+            .remove::<Transform>()
+            .insert(NotReflected);
+
+        let texts = run_ui(&ctx, &mut world, |world, ui| {
+            ui_for_entity(world, moved, ui);
+            ui_for_entity(world, despawned, ui);
+            ui_for_world(world, ui);
+        });
+        // the despawned entity shows an error, and the moved entity shows the components of its new archetype
+        assert!(texts.contains(&does_not_exist));
+        assert!(texts.iter().any(|text| text == "Name"));
+        assert!(texts.iter().any(|text| text == "NotReflected"));
+        assert!(!texts.iter().any(|text| text == "Transform"));
+        assert!(texts.iter().any(|text| text.starts_with("1 entities")));
+    }
+
+    #[test]
+    fn change_highlight_of_inline_component_does_not_spill() {
+        let mut world = World::new();
+        let type_registry = AppTypeRegistry::default();
+        {
+            let mut type_registry = type_registry.write();
+            type_registry.register::<Alpha>();
+            type_registry.register::<Beta>();
+        }
+        let entity = world.spawn((Alpha(0.0), Beta(0.0))).id();
+        world.clear_trackers();
+        world.get_mut::<Alpha>(entity).unwrap().0 = 1.0;
+
+        let mut params = WorldInspectorParams {
+            inline_single_field: true,
+            highlight_changes: true,
+            ..Default::default()
+        };
+        let ctx = egui::Context::default();
+        run_ui(&ctx, &mut world, |world, ui| {
+            let style = ui.style().clone();
+            ui_for_entity_components(
+                &mut world.into(),
+                None,
+                entity,
+                ui,
+                egui::Id::NULL,
+                &type_registry.read(),
+                &mut params,
+            );
+            assert_eq!(*ui.style(), style);
+        });
+        assert!(params.last_changed.keys().any(|&(e, _)| e == entity));
+    }
+}
@@ -0,0 +1,1088 @@
+use std::{
+    any::TypeId,
+    borrow::Cow,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy_core::Name;
+use bevy_ecs::{archetype::ArchetypeId, component::ComponentId, prelude::*};
+use bevy_hierarchy::Parent;
+use bevy_reflect::{Reflect, ReflectRef, TypeRegistry};
+use bevy_time::{Fixed, Real, Time, Virtual};
+use bevy_transform::TransformBundle;
+use bevy_utils::{HashMap, HashSet, Instant};
+
+use super::Filter;
+use crate::{
+    reflect_inspector::NumberDefaults,
+    utils::guess_entity_name::{guess_entity_name, guess_entity_name_without_id},
+};
+
+/// Configuration and persistent UI state of the world inspector.
+///
+/// The world inspector functions like [`ui_for_world`](super::ui_for_world) read this resource from the world if it exists.
+/// Otherwise they use the default params, keeping their UI state like the filters in egui memory instead of inserting the resource.
+/// The windows of the [`WorldInspectorPlugin`](crate::quick::WorldInspectorPlugin) don't use this resource,
+/// but have their own params in [`WorldInspectorWindows`](crate::quick::WorldInspectorWindows).
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::bevy_inspector::WorldInspectorParams;
+/// use bevy_inspector_egui::quick::{WorldInspectorPlugin, WorldInspectorWindows};
+///
+/// fn main() {
+///     let mut params = WorldInspectorParams::default();
+///     params.fuzzy_filter = true;
+///
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(WorldInspectorPlugin::new().with_params(params))
+///         .add_systems(Update, toggle_read_only)
+///         .run();
+/// }
+///
+/// // the params of a window can be changed later using its title
+/// fn toggle_read_only(keys: Res<ButtonInput<KeyCode>>, mut windows: ResMut<WorldInspectorWindows>) {
+///     if let Some(params) = windows.get_mut("World Inspector") {
+///         if keys.just_pressed(KeyCode::KeyR) {
+///             params.read_only = !params.read_only;
+///         }
+///     }
+/// }
+/// ```
+#[derive(Resource)]
+pub struct WorldInspectorParams {
+    /// Whether the [`WorldInspectorPlugin`](crate::quick::WorldInspectorPlugin) window using these params is shown,
+    /// toggled by its [toggle key](crate::quick::WorldInspectorPlugin::with_toggle_key).
+    ///
+    /// Each window has its own params, see [`WorldInspectorWindows`](crate::quick::WorldInspectorWindows).
+    pub enabled: bool,
+    /// Only show entities whose name (or the name of one of their descendants) matches this text.
+    ///
+    /// Matches case-insensitive substrings, unless [`fuzzy_filter`](WorldInspectorParams::fuzzy_filter) is enabled.
+    pub entity_filter: String,
+    /// Whether [`entity_filter`](WorldInspectorParams::entity_filter) uses fuzzy matching
+    pub fuzzy_filter: bool,
+    /// Only show entities without a [`Name`](bevy_core::Name) (or with a descendant without a name), to find entities which should be named
+    pub only_unnamed_entities: bool,
+    /// Only show components whose name contains this text (case-insensitive).
+    ///
+    /// Entities with at least one matching component are expanded when the filter is edited.
+    pub component_filter: String,
+    /// Whether [`component_filter`](WorldInspectorParams::component_filter) also matches the names of struct fields inside reflected components, like `speed`.
+    ///
+    /// Matching fields are highlighted, and their components are expanded when the filter is edited.
+    pub match_field_names: bool,
+    /// Only show entities which have a component matching [`component_filter`](WorldInspectorParams::component_filter),
+    /// and the ancestors of those entities
+    pub filter_entities_by_component: bool,
+    /// Only show components which were changed since the world inspector was last drawn.
+    ///
+    /// Change detection is only available for reflected components, so unregistered and zero-sized components are hidden.
+    pub show_only_changed: bool,
+    /// Briefly highlight the headers of components when they are changed, fading out over half a second.
+    pub highlight_changes: bool,
+    /// Resources which will not be listed in [`ui_for_resources`](super::ui_for_resources).
+    ///
+    /// See also [`WorldInspectorParams::ignore_resource`].
+    pub ignore_resources: HashSet<TypeId>,
+    /// Resources which are displayed, but can't be edited, because they are overwritten every frame anyway.
+    ///
+    /// Contains the [`Time`] resources by default. See also [`WorldInspectorParams::read_only_resource`].
+    pub read_only_resources: HashSet<TypeId>,
+    /// Components which will not be listed for an entity, unless [`show_ignored_components`](WorldInspectorParams::show_ignored_components) is enabled.
+    ///
+    /// See also [`WorldInspectorParams::ignore_component`].
+    pub ignore_components: HashSet<TypeId>,
+    /// Load [`ignore_components`](WorldInspectorParams::ignore_components) from this JSON file when the world inspector is first shown,
+    /// and save them to it whenever they change.
+    ///
+    /// The components are stored by their type path. A missing file is treated as an empty list,
+    /// and types which aren't in the type registry anymore are kept in the file, but otherwise ignored.
+    pub save_path: Option<PathBuf>,
+    /// Entities with any of these components are hidden in the world inspector, together with their children.
+    ///
+    /// See also [`WorldInspectorParams::ignore_entities_with`].
+    pub ignore_entities: HashSet<TypeId>,
+    /// Hide entities whose components are all in [`ignore_components`](WorldInspectorParams::ignore_components),
+    /// unless one of their descendants has other components
+    pub hide_empty_entities: bool,
+    /// Whether to list the components in [`ignore_components`](WorldInspectorParams::ignore_components) anyway
+    pub show_ignored_components: bool,
+    /// Whether to show the entity id and generation next to the entity name, like `Player 5v0`
+    pub show_entity_id: bool,
+    /// Whether to show the number of components next to the entity name, like `Player (7)`
+    pub show_component_count: bool,
+    /// Whether to show the number of children next to the entity name, like `Level [3 children]`
+    pub show_child_count: bool,
+    /// Whether to show the number of entities, archetypes and component types above the entity list
+    pub show_world_stats: bool,
+    /// The order in which the components of an entity are listed
+    pub component_order: ComponentOrder,
+    /// Whether the "Despawn" button of an entity also despawns its children
+    pub despawn_recursive: bool,
+    /// Whether the "Duplicate" button of an entity also duplicates its children
+    pub duplicate_children: bool,
+    /// Entities shown in a separate section above the other entities. Despawned entities are removed automatically.
+    pub pinned: Vec<Entity>,
+    /// Entities selected by ctrl-clicking their header. When there are at least two, their common components are compared side-by-side.
+    pub multi_selection: Vec<Entity>,
+    /// Only show this entity and its children in [`ui_for_world_entities`](super::ui_for_world_entities), set by the "Focus" button of an entity
+    pub focused_entity: Option<Entity>,
+    /// While filtering by name, list all matching entities with the path of their ancestors, like `World > Level > Enemy`,
+    /// instead of the nested hierarchy. Clicking a result focuses it, see [`focused_entity`](WorldInspectorParams::focused_entity).
+    pub flat_search_results: bool,
+    /// How [`ui_for_world_entities`](super::ui_for_world_entities) groups the entities
+    pub display_mode: DisplayMode,
+    /// Show entities, resources and assets without allowing edits, and hide buttons like "Despawn".
+    ///
+    /// Custom [`InspectorPrimitive`](crate::inspector_egui_impls::InspectorPrimitive)s are displayed using their `ui_readonly` method.
+    pub read_only: bool,
+    /// Keep showing the component values captured when this was enabled, instead of the live values.
+    ///
+    /// Frozen values are read-only. Components added after freezing are hidden until this is disabled again.
+    pub frozen: bool,
+    /// Navigate the entity list with the keyboard while no widget has keyboard focus.
+    ///
+    /// The up and down arrows move the cursor between the visible entities, right and left expand and collapse the entity,
+    /// and enter gives keyboard focus to the entity so that its fields can be reached using tab. Escape returns to the entity list.
+    pub keyboard_navigation: bool,
+    /// Lay out the fields of structs with many primitive fields, like a matrix, in wrapping rows instead of one field per row.
+    ///
+    /// Applies to structs with more than [`WRAP_FIELDS_MIN`](crate::reflect_inspector::WRAP_FIELDS_MIN) fields which are all primitive values.
+    pub wrap_fields: bool,
+    /// Show values which have no [`InspectorEguiImpl`](crate::inspector_egui_impls::InspectorEguiImpl) as read-only text
+    /// using their [`Debug`](std::fmt::Debug) implementation, if it is reflected with `#[reflect(Debug)]`.
+    ///
+    /// The text is cut off after 200 characters. Disabled by default.
+    pub debug_fallback: bool,
+    /// Show components with a single primitive field, like `Health(f32)`, in one row instead of a collapsible header.
+    /// Disabled by default.
+    pub inline_single_field: bool,
+    /// Show the (truncated) [`Debug`](std::fmt::Debug) output of components next to their name while their header is collapsed
+    pub component_summaries: bool,
+    /// Show buttons to reset components and struct fields to their [`Default`] value
+    pub reset_buttons: bool,
+    /// The egui style used inside the world inspector, for example to make it denser than the rest of your UI.
+    ///
+    /// Uses the style of the egui context if `None`.
+    pub style: Option<egui::Style>,
+    /// Recompute the filtered list of root entities at most this often, instead of every frame.
+    ///
+    /// Only the root list is cached: the hierarchy below the roots and the components of expanded entities are still
+    /// displayed every frame. Editing the filter updates the list immediately.
+    ///
+    /// Independently of this, egui doesn't run the contents of collapsed windows and headers, so a collapsed
+    /// [`WorldInspectorPlugin`](crate::quick::WorldInspectorPlugin) window skips the world inspector UI entirely.
+    pub update_interval: Option<Duration>,
+    /// How much numbers change per pixel dragged, unless overridden by their [`NumberOptions`](crate::inspector_options::std_options::NumberOptions)
+    pub drag_speed: f32,
+    /// The number of decimals numbers are displayed with, or `None` to choose them based on the drag speed
+    pub decimals: Option<usize>,
+    /// Evaluate arithmetic expressions like `pi/2` or `1024*2` typed into number fields.
+    ///
+    /// Invalid expressions keep the previous value and outline the field in red.
+    pub allow_expressions: bool,
+    /// Only show root entities matching this filter, set by [`WorldInspectorPlugin::with_entity_filter`](crate::quick::WorldInspectorPlugin::with_entity_filter)
+    pub(crate) root_entity_filter: Option<EntityFilter>,
+    /// Custom entity names, see [`WorldInspectorParams::set_entity_name_fn`]
+    pub(crate) entity_name_fn: Option<EntityNameFn>,
+    /// Inserts the components of entities spawned with the "Spawn entity" button, see [`WorldInspectorParams::set_spawn_fn`]
+    pub(crate) spawn_fn: Option<SpawnFn>,
+    /// The entity spawned with the "Spawn entity" button during the last UI pass, which is expanded
+    pub(crate) spawned_entity: Option<Entity>,
+    /// Names shown for components instead of their pretty type name, see [`WorldInspectorParams::set_display_name`]
+    pub(crate) display_names: HashMap<TypeId, String>,
+    /// The most recent "Expand all"/"Collapse all" action, which newly appearing entities follow
+    pub(crate) entities_expanded: bool,
+    /// Set for the frame in which "Expand all"/"Collapse all" was pressed
+    pub(crate) set_entities_expanded: Option<bool>,
+    /// Set for the frame in which the [`component_filter`](WorldInspectorParams::component_filter) was edited
+    pub(crate) component_filter_changed: bool,
+    /// Entities whose "Despawn" button was clicked during the current UI pass
+    pub(crate) pending_despawns: Vec<Entity>,
+    /// The entity dragged onto the header of its new parent during the current UI pass, or onto the root drop zone
+    pub(crate) pending_reparent: Option<(Entity, Option<Entity>)>,
+    /// The egui frame and time in which a change of the displayed components was last detected.
+    /// The frame is shown when hovering their header, and the time is used for the [`highlight_changes`](WorldInspectorParams::highlight_changes) fade.
+    pub(crate) last_changed: HashMap<(Entity, ComponentId), (u64, f64)>,
+    /// The entity whose header is hovered during the current UI pass, see [`InspectorSelection`]
+    pub(crate) hovered_entity: Option<Entity>,
+    /// The entity whose header was clicked during the current UI pass, see [`InspectorSelection`]
+    pub(crate) clicked_entity: Option<Entity>,
+    pub(crate) navigation: EntityNavigation,
+    /// The [`InspectorSelection::selected`] entity after the last UI pass, to detect when it is changed from outside
+    pub(crate) last_selected: Option<Entity>,
+    /// The selected entity whose header should be scrolled to, and its ancestors which need to be expanded for that
+    pub(crate) reveal: Option<(Entity, Vec<Entity>)>,
+    /// The root entity lists of [`update_interval`](WorldInspectorParams::update_interval), by UI, query filter and root entity filter
+    pub(crate) entity_lists: HashMap<egui::Id, CachedEntityList>,
+    pub(crate) component_cache: ComponentCache,
+    pub(crate) persisted_ignore_list: PersistedIgnoreList,
+    /// The component values captured when [`frozen`](WorldInspectorParams::frozen) was enabled
+    pub(crate) snapshot: Option<HashMap<(Entity, TypeId), Box<dyn Reflect>>>,
+}
+
+/// The entities the user is interacting with in the world inspector.
+///
+/// This resource is updated by the world inspector and can be read by your systems, for example to draw a gizmo around the hovered entity.
+/// When your systems change [`selected`](InspectorSelection::selected), for example when clicking an entity in the viewport,
+/// the world inspector expands its ancestors and scrolls to it.
+///
+/// ```rust
+/// use bevy_ecs::prelude::*;
+/// use bevy_inspector_egui::bevy_inspector::InspectorSelection;
+///
+/// fn highlight(selection: Option<Res<InspectorSelection>>) {
+///     let Some(selection) = selection else { return };
+///     if let Some(entity) = selection.hovered {
+///         // draw an outline around `entity`
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct InspectorSelection {
+    /// The entity whose header is currently hovered
+    pub hovered: Option<Entity>,
+    /// The entity whose header was clicked most recently. Unlike [`hovered`](InspectorSelection::hovered), this stays set until another entity is clicked,
+    /// or until the entity is despawned.
+    pub selected: Option<Entity>,
+    /// The egui frame in which `hovered` was last updated
+    frame: u64,
+}
+
+impl InspectorSelection {
+    /// Publishes the hovered and clicked entity of the current UI pass.
+    ///
+    /// When multiple world inspectors are shown, an entity hovered in any of them during the frame is kept.
+    pub(crate) fn update(world: &mut World, frame: u64, params: &mut WorldInspectorParams) {
+        let hovered = params.hovered_entity.take();
+        let clicked = params.clicked_entity.take();
+
+        let selected_despawned = world
+            .get_resource::<InspectorSelection>()
+            .and_then(|selection| selection.selected)
+            .is_some_and(|entity| world.get_entity(entity).is_none());
+
+        let mut selection = world.get_resource_or_insert_with(InspectorSelection::default);
+        let hovered = if selection.frame == frame {
+            hovered.or(selection.hovered)
+        } else {
+            hovered
+        };
+        selection.bypass_change_detection().frame = frame;
+        if selection.hovered != hovered {
+            selection.hovered = hovered;
+        }
+        if clicked.is_some() && selection.selected != clicked {
+            selection.selected = clicked;
+        }
+        if clicked.is_none() && selected_despawned {
+            selection.selected = None;
+        }
+        params.last_selected = selection.selected;
+        params.reveal = None;
+        params.spawned_entity = None;
+    }
+
+    /// Prepares scrolling to the selected entity if it was changed since the last UI pass
+    pub(crate) fn reveal_changed(world: &World, params: &mut WorldInspectorParams) {
+        let selected = world
+            .get_resource::<InspectorSelection>()
+            .and_then(|selection| selection.selected);
+        if selected == params.last_selected {
+            return;
+        }
+        params.last_selected = selected;
+        params.reveal = selected.map(|entity| {
+            let ancestors = std::iter::successors(world.get::<Parent>(entity), |parent| {
+                world.get::<Parent>(parent.get())
+            })
+            .map(Parent::get)
+            .take(1024)
+            .collect();
+            (entity, ancestors)
+        });
+    }
+}
+
+/// State of the [`keyboard_navigation`](WorldInspectorParams::keyboard_navigation)
+#[derive(Default)]
+pub(crate) struct EntityNavigation {
+    /// The entity under the cursor
+    pub(crate) focused: Option<Entity>,
+    /// The entity headers in the order they were drawn during the last UI pass
+    pub(crate) visible: Vec<Entity>,
+    /// Scroll to the focused entity, set when the cursor moved
+    pub(crate) scroll: bool,
+    /// Expand or collapse the focused entity
+    pub(crate) open: Option<bool>,
+    /// Give keyboard focus to the header of the focused entity
+    pub(crate) focus_header: bool,
+}
+
+/// State of the [`save_path`](WorldInspectorParams::save_path) file
+#[derive(Default)]
+pub(crate) struct PersistedIgnoreList {
+    /// The path the ignore list was loaded from
+    loaded_from: Option<PathBuf>,
+    /// The ignored components as they were last loaded or saved
+    saved: HashSet<TypeId>,
+    /// Type paths from the file which aren't in the type registry
+    unresolved: Vec<String>,
+}
+
+pub(crate) struct CachedEntityList {
+    pub(crate) updated: Instant,
+    pub(crate) filter: Filter,
+    pub(crate) entities: Vec<Entity>,
+}
+
+/// The order of the components of an entity, see [`WorldInspectorParams::component_order`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ComponentOrder {
+    /// The order in which they are stored in the archetype of the entity
+    Archetype,
+    /// Sorted by their name, falling back to the full type name for components with the same name
+    #[default]
+    Alphabetical,
+    /// The order in which the component types were first registered in the world
+    RegistryOrder,
+}
+
+/// How the entities of the world inspector are grouped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayMode {
+    /// Show the root entities, with their children nested inside
+    #[default]
+    Hierarchy,
+    /// Show one header per archetype, listing its components and entities
+    ByArchetype,
+    /// Show all entities in one list, without nesting the children inside their parents
+    Flat,
+}
+
+pub(crate) type EntityFilter = Arc<dyn Fn(&World, Entity) -> bool + Send + Sync>;
+
+type EntityNameFn = Arc<dyn Fn(&World, Entity) -> String + Send + Sync>;
+
+type SpawnFn = Arc<dyn Fn(&mut EntityWorldMut) + Send + Sync>;
+
+/// `(pretty name, component id, type id, size)` of a component
+pub(crate) type ComponentEntry = (String, ComponentId, Option<TypeId>, usize);
+
+/// The component lists of the archetypes, see [`components_of_entity_cached`](super::components_of_entity_cached)
+#[derive(Default)]
+pub(crate) struct ComponentCache {
+    pub(crate) order: ComponentOrder,
+    pub(crate) by_archetype: HashMap<ArchetypeId, Arc<[ComponentEntry]>>,
+}
+
+impl Default for WorldInspectorParams {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            entity_filter: String::new(),
+            fuzzy_filter: false,
+            only_unnamed_entities: false,
+            component_filter: String::new(),
+            match_field_names: false,
+            filter_entities_by_component: false,
+            show_only_changed: false,
+            highlight_changes: false,
+            ignore_resources: HashSet::default(),
+            read_only_resources: [
+                TypeId::of::<Time>(),
+                TypeId::of::<Time<Real>>(),
+                TypeId::of::<Time<Virtual>>(),
+                TypeId::of::<Time<Fixed>>(),
+            ]
+            .into_iter()
+            .collect(),
+            ignore_components: HashSet::default(),
+            ignore_entities: HashSet::default(),
+            save_path: None,
+            persisted_ignore_list: PersistedIgnoreList::default(),
+            snapshot: None,
+            hide_empty_entities: false,
+            show_ignored_components: false,
+            show_entity_id: true,
+            show_component_count: true,
+            show_child_count: true,
+            show_world_stats: true,
+            component_order: ComponentOrder::default(),
+            despawn_recursive: true,
+            duplicate_children: false,
+            pinned: Vec::new(),
+            multi_selection: Vec::new(),
+            focused_entity: None,
+            flat_search_results: false,
+            display_mode: DisplayMode::default(),
+            read_only: false,
+            frozen: false,
+            keyboard_navigation: false,
+            wrap_fields: false,
+            debug_fallback: false,
+            inline_single_field: false,
+            component_summaries: false,
+            reset_buttons: true,
+            style: None,
+            update_interval: None,
+            drag_speed: NumberDefaults::default().drag_speed,
+            decimals: None,
+            allow_expressions: false,
+            root_entity_filter: None,
+            entity_name_fn: None,
+            spawn_fn: None,
+            spawned_entity: None,
+            display_names: HashMap::default(),
+            entities_expanded: false,
+            set_entities_expanded: None,
+            component_filter_changed: false,
+            pending_despawns: Vec::new(),
+            pending_reparent: None,
+            last_changed: HashMap::default(),
+            hovered_entity: None,
+            clicked_entity: None,
+            navigation: EntityNavigation::default(),
+            last_selected: None,
+            reveal: None,
+            entity_lists: HashMap::default(),
+            component_cache: ComponentCache::default(),
+        }
+    }
+}
+
+impl WorldInspectorParams {
+    /// Copies the configuration, like the ignored components and the display options, but not the UI state like the filters or the pinned entities.
+    ///
+    /// Used for entities displayed inside of component values, which shouldn't share the UI state of the surrounding world inspector.
+    pub(crate) fn clone_config(&self) -> Self {
+        WorldInspectorParams {
+            fuzzy_filter: self.fuzzy_filter,
+            match_field_names: self.match_field_names,
+            show_only_changed: self.show_only_changed,
+            highlight_changes: self.highlight_changes,
+            ignore_resources: self.ignore_resources.clone(),
+            read_only_resources: self.read_only_resources.clone(),
+            ignore_components: self.ignore_components.clone(),
+            ignore_entities: self.ignore_entities.clone(),
+            hide_empty_entities: self.hide_empty_entities,
+            show_ignored_components: self.show_ignored_components,
+            show_entity_id: self.show_entity_id,
+            show_component_count: self.show_component_count,
+            show_child_count: self.show_child_count,
+            show_world_stats: self.show_world_stats,
+            component_order: self.component_order,
+            despawn_recursive: self.despawn_recursive,
+            duplicate_children: self.duplicate_children,
+            flat_search_results: self.flat_search_results,
+            display_mode: self.display_mode,
+            read_only: self.read_only,
+            keyboard_navigation: self.keyboard_navigation,
+            wrap_fields: self.wrap_fields,
+            debug_fallback: self.debug_fallback,
+            inline_single_field: self.inline_single_field,
+            component_summaries: self.component_summaries,
+            reset_buttons: self.reset_buttons,
+            style: self.style.clone(),
+            update_interval: self.update_interval,
+            drag_speed: self.drag_speed,
+            decimals: self.decimals,
+            allow_expressions: self.allow_expressions,
+            root_entity_filter: self.root_entity_filter.clone(),
+            entity_name_fn: self.entity_name_fn.clone(),
+            spawn_fn: self.spawn_fn.clone(),
+            display_names: self.display_names.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Hide the resource `T` in the list of resources.
+    pub fn ignore_resource<T: Resource>(&mut self) -> &mut Self {
+        self.ignore_resources.insert(TypeId::of::<T>());
+        self
+    }
+
+    /// Show the resource `T` again after it was hidden using [`WorldInspectorParams::ignore_resource`].
+    pub fn unignore_resource<T: Resource>(&mut self) -> &mut Self {
+        self.ignore_resources.remove(&TypeId::of::<T>());
+        self
+    }
+
+    /// Show the resource `T` without allowing edits.
+    pub fn read_only_resource<T: Resource>(&mut self) -> &mut Self {
+        self.read_only_resources.insert(TypeId::of::<T>());
+        self
+    }
+
+    /// Allow editing the resource `T` again, after [`WorldInspectorParams::read_only_resource`].
+    pub fn editable_resource<T: Resource>(&mut self) -> &mut Self {
+        self.read_only_resources.remove(&TypeId::of::<T>());
+        self
+    }
+
+    /// Hide the component `T` in the component list of entities.
+    pub fn ignore_component<T: Component>(&mut self) -> &mut Self {
+        self.ignore_components.insert(TypeId::of::<T>());
+        self
+    }
+
+    /// Show the component `T` again after it was hidden using [`WorldInspectorParams::ignore_component`].
+    pub fn unignore_component<T: Component>(&mut self) -> &mut Self {
+        self.ignore_components.remove(&TypeId::of::<T>());
+        self
+    }
+
+    /// Hide entities with the component `T` in the world inspector, for example the entities of your editor UI.
+    pub fn ignore_entities_with<T: Component>(&mut self) -> &mut Self {
+        self.ignore_entities.insert(TypeId::of::<T>());
+        self
+    }
+
+    /// Show entities with the component `T` again after they were hidden using [`WorldInspectorParams::ignore_entities_with`].
+    pub fn unignore_entities_with<T: Component>(&mut self) -> &mut Self {
+        self.ignore_entities.remove(&TypeId::of::<T>());
+        self
+    }
+
+    /// Show all ignored resources, components and entities again.
+    pub fn clear_ignored(&mut self) -> &mut Self {
+        self.ignore_resources.clear();
+        self.ignore_components.clear();
+        self.ignore_entities.clear();
+        self
+    }
+
+    /// Whether the entity is hidden by [`ignore_entities`](WorldInspectorParams::ignore_entities)
+    pub(crate) fn is_entity_ignored(&self, world: &World, entity: Entity) -> bool {
+        !self.ignore_entities.is_empty()
+            && world.get_entity(entity).is_some_and(|entity| {
+                self.ignore_entities
+                    .iter()
+                    .any(|&type_id| entity.contains_type_id(type_id))
+            })
+    }
+
+    /// Whether the component with the given type is hidden by [`ignore_components`](WorldInspectorParams::ignore_components)
+    pub(crate) fn is_component_ignored(&self, type_id: Option<TypeId>) -> bool {
+        !self.show_ignored_components
+            && type_id.is_some_and(|type_id| self.ignore_components.contains(&type_id))
+    }
+
+    /// Use `f` to name entities instead of [`guess_entity_name`].
+    ///
+    /// The entity id is still shown next to the name, unless [`show_entity_id`](WorldInspectorParams::show_entity_id) is disabled.
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_inspector_egui::bevy_inspector::{guess_entity_name, WorldInspectorParams};
+    /// # use bevy_inspector_egui::quick::WorldInspectorPlugin;
+    /// #[derive(Component)]
+    /// struct Label(&'static str);
+    ///
+    /// let mut params = WorldInspectorParams::default();
+    /// params.set_entity_name_fn(|world, entity| match world.get::<Label>(entity) {
+    ///     Some(label) => label.0.to_string(),
+    ///     None => guess_entity_name(world, entity),
+    /// });
+    ///
+    /// App::new()
+    ///     .add_plugins(DefaultPlugins)
+    ///     .add_plugins(WorldInspectorPlugin::new().with_params(params))
+    ///     .run();
+    /// ```
+    pub fn set_entity_name_fn(
+        &mut self,
+        f: impl Fn(&World, Entity) -> String + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.entity_name_fn = Some(Arc::new(f));
+        self
+    }
+
+    /// Removes the function set by [`WorldInspectorParams::set_entity_name_fn`]
+    pub fn clear_entity_name_fn(&mut self) -> &mut Self {
+        self.entity_name_fn = None;
+        self
+    }
+
+    /// Use `f` to insert the components of entities spawned with the "Spawn entity" button,
+    /// instead of a [`Name`](bevy_core::Name) and a [`TransformBundle`](bevy_transform::TransformBundle).
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_inspector_egui::bevy_inspector::WorldInspectorParams;
+    /// # use bevy_inspector_egui::quick::WorldInspectorPlugin;
+    /// let mut params = WorldInspectorParams::default();
+    /// params.set_spawn_fn(|entity| {
+    ///     entity.insert(Name::new("Enemy"));
+    /// });
+    ///
+    /// App::new()
+    ///     .add_plugins(DefaultPlugins)
+    ///     .add_plugins(WorldInspectorPlugin::new().with_params(params))
+    ///     .run();
+    /// ```
+    pub fn set_spawn_fn(
+        &mut self,
+        f: impl Fn(&mut EntityWorldMut) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.spawn_fn = Some(Arc::new(f));
+        self
+    }
+
+    /// Spawns an entity using the [spawn function](WorldInspectorParams::set_spawn_fn), and selects it
+    pub(crate) fn spawn_entity(&mut self, world: &mut World) {
+        let mut entity = world.spawn_empty();
+        match &self.spawn_fn {
+            Some(f) => f(&mut entity),
+            None => {
+                entity.insert((Name::new("Entity"), TransformBundle::default()));
+            }
+        }
+        let entity = entity.id();
+        self.spawned_entity = Some(entity);
+        world
+            .get_resource_or_insert_with(InspectorSelection::default)
+            .selected = Some(entity);
+    }
+
+    /// Show the component `T` as `name`, instead of its shortened type name
+    pub fn set_display_name<T: 'static>(&mut self, name: impl Into<String>) -> &mut Self {
+        self.display_names.insert(TypeId::of::<T>(), name.into());
+        self.component_cache.by_archetype.clear();
+        self
+    }
+
+    /// Show the component `T` using its shortened type name again, after [`WorldInspectorParams::set_display_name`]
+    pub fn clear_display_name<T: 'static>(&mut self) -> &mut Self {
+        self.display_names.remove(&TypeId::of::<T>());
+        self.component_cache.by_archetype.clear();
+        self
+    }
+
+    /// The name of the entity including its id, like `Player (5v0)`
+    pub(crate) fn entity_name(&self, world: &World, entity: Entity) -> String {
+        match &self.entity_name_fn {
+            Some(f) => format!("{} ({entity:?})", f(world, entity)),
+            None => guess_entity_name(world, entity),
+        }
+    }
+
+    /// The name of the entity without its id
+    pub(crate) fn entity_name_without_id<'w>(
+        &self,
+        world: &'w World,
+        entity: Entity,
+    ) -> Cow<'w, str> {
+        match &self.entity_name_fn {
+            Some(f) => Cow::Owned(f(world, entity)),
+            None => guess_entity_name_without_id(world, entity),
+        }
+    }
+
+    /// The number settings passed to the inspectors via the [`Context`](crate::reflect_inspector::Context)
+    pub(crate) fn number_defaults(&self) -> NumberDefaults {
+        NumberDefaults {
+            drag_speed: self.drag_speed,
+            decimals: self.decimals,
+            allow_expressions: self.allow_expressions,
+        }
+    }
+
+    /// Whether the component with the given (pretty) type name passes the [`component_filter`](WorldInspectorParams::component_filter)
+    pub(crate) fn component_matches(&self, name: &str) -> bool {
+        self.component_filter.is_empty()
+            || name
+                .to_lowercase()
+                .contains(&self.component_filter.to_lowercase())
+    }
+
+    /// The lowercase component filter if it should be matched against field names, see [`match_field_names`](WorldInspectorParams::match_field_names)
+    pub(crate) fn field_filter(&self) -> Option<String> {
+        (self.match_field_names && !self.component_filter.is_empty())
+            .then(|| self.component_filter.to_lowercase())
+    }
+
+    /// Whether a struct field (of the value or nested values) matches the [`field_filter`](WorldInspectorParams::field_filter)
+    pub(crate) fn fields_match(&self, value: &dyn Reflect) -> bool {
+        fn matches(value: &dyn Reflect, filter: &str, depth: usize) -> bool {
+            if depth > 8 {
+                return false;
+            }
+            match value.reflect_ref() {
+                ReflectRef::Struct(value) => (0..value.field_len()).any(|i| {
+                    value.name_at(i).unwrap().to_lowercase().contains(filter)
+                        || matches(value.field_at(i).unwrap(), filter, depth + 1)
+                }),
+                ReflectRef::TupleStruct(value) => value
+                    .iter_fields()
+                    .any(|field| matches(field, filter, depth + 1)),
+                ReflectRef::Enum(value) => (0..value.field_len()).any(|i| {
+                    value
+                        .name_at(i)
+                        .is_some_and(|name| name.to_lowercase().contains(filter))
+                        || matches(value.field_at(i).unwrap(), filter, depth + 1)
+                }),
+                _ => false,
+            }
+        }
+
+        self.field_filter()
+            .is_some_and(|filter| matches(value, &filter, 0))
+    }
+
+    /// Shows the "Expand all"/"Collapse all" buttons
+    pub(crate) fn expand_collapse_ui(&mut self, ui: &mut egui::Ui) {
+        self.set_entities_expanded = None;
+        ui.horizontal(|ui| {
+            if ui.button("Expand all").clicked() {
+                self.set_entities_expanded = Some(true);
+            }
+            if ui.button("Collapse all").clicked() {
+                self.set_entities_expanded = Some(false);
+            }
+        });
+        if let Some(expanded) = self.set_entities_expanded {
+            self.entities_expanded = expanded;
+        }
+    }
+
+    /// Shows the commonly changed settings, applying to all world inspectors immediately
+    pub(crate) fn settings_ui(&mut self, ui: &mut egui::Ui) {
+        ui.label("Components");
+        ui.radio_value(
+            &mut self.component_order,
+            ComponentOrder::Alphabetical,
+            "Sort alphabetically",
+        );
+        ui.radio_value(
+            &mut self.component_order,
+            ComponentOrder::Archetype,
+            "Archetype order",
+        );
+        ui.radio_value(
+            &mut self.component_order,
+            ComponentOrder::RegistryOrder,
+            "Registration order",
+        );
+        ui.checkbox(&mut self.inline_single_field, "Inline single fields");
+        ui.checkbox(&mut self.component_summaries, "Summaries in headers");
+        ui.checkbox(&mut self.highlight_changes, "Highlight changes");
+        ui.separator();
+
+        ui.label("Entities");
+        ui.checkbox(&mut self.show_entity_id, "Show ids");
+        ui.checkbox(&mut self.show_component_count, "Show component count");
+        ui.checkbox(&mut self.show_child_count, "Show child count");
+        ui.checkbox(&mut self.hide_empty_entities, "Hide empty entities");
+        ui.checkbox(&mut self.show_world_stats, "Show world stats");
+        ui.separator();
+
+        ui.checkbox(&mut self.read_only, "Read-only");
+    }
+
+    /// Reacts to the keys of the [`keyboard_navigation`](WorldInspectorParams::keyboard_navigation), using the entities drawn during the last UI pass
+    pub(crate) fn keyboard_navigation_ui(&mut self, ui: &egui::Ui) {
+        let navigation = &mut self.navigation;
+        let visible = std::mem::take(&mut navigation.visible);
+        navigation.scroll = false;
+        navigation.open = None;
+        navigation.focus_header = false;
+
+        if !self.keyboard_navigation || ui.memory(|memory| memory.focused().is_some()) {
+            return;
+        }
+
+        let index = navigation
+            .focused
+            .and_then(|focused| visible.iter().position(|&entity| entity == focused));
+        let (down, up, right, left, enter) = ui.input(|input| {
+            (
+                input.key_pressed(egui::Key::ArrowDown),
+                input.key_pressed(egui::Key::ArrowUp),
+                input.key_pressed(egui::Key::ArrowRight),
+                input.key_pressed(egui::Key::ArrowLeft),
+                input.key_pressed(egui::Key::Enter),
+            )
+        });
+
+        let new_index = match index {
+            Some(index) if down => Some((index + 1).min(visible.len().saturating_sub(1))),
+            Some(index) if up => Some(index.saturating_sub(1)),
+            None if down || up => Some(0),
+            _ => index,
+        };
+        if new_index != index {
+            navigation.focused = new_index.and_then(|index| visible.get(index).copied());
+            navigation.scroll = true;
+        }
+
+        if right || enter {
+            navigation.open = Some(true);
+        } else if left {
+            navigation.open = Some(false);
+        }
+        navigation.focus_header = enter;
+    }
+
+    /// Applies the expand/collapse state to an entity header
+    pub(crate) fn entity_header(&self, header: egui::CollapsingHeader) -> egui::CollapsingHeader {
+        header
+            .default_open(self.entities_expanded)
+            .open(self.set_entities_expanded)
+    }
+
+    /// Captures the reflected components of all entities when [`frozen`](WorldInspectorParams::frozen) was enabled, and drops them when it was disabled
+    pub(crate) fn sync_snapshot(&mut self, world: &World, type_registry: &TypeRegistry) {
+        if !self.frozen {
+            self.snapshot = None;
+            return;
+        }
+        if self.snapshot.is_some() {
+            return;
+        }
+
+        let mut snapshot = HashMap::default();
+        for entity in world.iter_entities() {
+            for component_id in entity.archetype().components() {
+                let Some(type_id) = world
+                    .components()
+                    .get_info(component_id)
+                    .and_then(|info| info.type_id())
+                else {
+                    continue;
+                };
+                let Some(reflect_component) =
+                    type_registry.get_type_data::<ReflectComponent>(type_id)
+                else {
+                    continue;
+                };
+                if let Some(value) = reflect_component.reflect(entity) {
+                    snapshot.insert((entity.id(), type_id), value.clone_value());
+                }
+            }
+        }
+        self.snapshot = Some(snapshot);
+    }
+
+    /// Loads the ignored components from the [`save_path`](WorldInspectorParams::save_path) if it changed, or saves them if they changed
+    pub(crate) fn sync_ignore_list(&mut self, type_registry: &TypeRegistry) {
+        let Some(path) = &self.save_path else {
+            return;
+        };
+
+        let persisted = &mut self.persisted_ignore_list;
+        if persisted.loaded_from.as_ref() != Some(path) {
+            persisted.loaded_from = Some(path.clone());
+            persisted.unresolved.clear();
+            let type_paths: Vec<String> = match std::fs::read_to_string(path) {
+                Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|error| {
+                    bevy_log::warn!("Failed to parse {}: {error}", path.display());
+                    Vec::new()
+                }),
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+                Err(error) => {
+                    bevy_log::warn!("Failed to read {}: {error}", path.display());
+                    Vec::new()
+                }
+            };
+            for type_path in type_paths {
+                match type_registry.get_with_type_path(&type_path) {
+                    Some(registration) => {
+                        self.ignore_components.insert(registration.type_id());
+                    }
+                    None => persisted.unresolved.push(type_path),
+                }
+            }
+            persisted.saved = self.ignore_components.clone();
+            return;
+        }
+
+        if persisted.saved == self.ignore_components {
+            return;
+        }
+        persisted.saved = self.ignore_components.clone();
+
+        let mut type_paths: Vec<&str> = self
+            .ignore_components
+            .iter()
+            .filter_map(|&type_id| type_registry.get(type_id))
+            .map(|registration| registration.type_info().type_path())
+            .chain(persisted.unresolved.iter().map(String::as_str))
+            .collect();
+        type_paths.sort();
+        let result = serde_json::to_string_pretty(&type_paths)
+            .map_err(std::io::Error::from)
+            .and_then(|json| std::fs::write(path, json));
+        if let Err(error) = result {
+            bevy_log::warn!("Failed to save {}: {error}", path.display());
+        }
+    }
+
+    /// Resets the style of `ui` to the [`style`](WorldInspectorParams::style) of the world inspector
+    pub(crate) fn reset_style(&self, ui: &mut egui::Ui) {
+        match &self.style {
+            Some(style) => ui.set_style(style.clone()),
+            None => ui.reset_style(),
+        }
+    }
+
+    /// Like [`WorldInspectorParams::scope`], but also applies the [`style`](WorldInspectorParams::style) to a child of `ui`.
+    ///
+    /// Without a [`WorldInspectorParams`] resource, the params are kept in the egui memory of `ui` instead of the default
+    /// being used every frame, so that UI state like the filters and expanded entities is kept between frames.
+    pub(crate) fn ui_scope<R>(
+        world: &mut World,
+        ui: &mut egui::Ui,
+        f: impl FnOnce(&mut World, &mut egui::Ui, &mut WorldInspectorParams) -> R,
+    ) -> R {
+        let fallback = (!world.contains_resource::<WorldInspectorParams>()).then(|| {
+            let id = ui.id().with("world inspector params");
+            ui.data_mut(|data| {
+                data.get_temp_mut_or_default::<Arc<Mutex<WorldInspectorParams>>>(id)
+                    .clone()
+            })
+        });
+        let run = |world: &mut World, params: &mut WorldInspectorParams| {
+            params.style_scope(ui, |ui, params| f(world, ui, params))
+        };
+
+        match fallback {
+            Some(params) => run(world, &mut params.lock().unwrap()),
+            None => Self::scope(world, run),
+        }
+    }
+
+    /// Runs `f` in a child ui using the [`style`](WorldInspectorParams::style) of the params
+    pub(crate) fn style_scope<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        f: impl FnOnce(&mut egui::Ui, &mut WorldInspectorParams) -> R,
+    ) -> R {
+        ui.scope(|ui| {
+            if let Some(style) = &self.style {
+                ui.set_style(style.clone());
+            }
+            f(ui, self)
+        })
+        .inner
+    }
+
+    /// Runs `f` with the [`WorldInspectorParams`] resource of the world, or with the default params if there is none.
+    ///
+    /// Nothing is inserted into the world. The resource is removed from the world while `f` runs, so that the rest
+    /// of the world can be used mutably, which is why the params are passed down to everything displayed inside.
+    pub(crate) fn scope<R>(
+        world: &mut World,
+        f: impl FnOnce(&mut World, &mut WorldInspectorParams) -> R,
+    ) -> R {
+        if !world.contains_resource::<WorldInspectorParams>() {
+            return f(world, &mut WorldInspectorParams::default());
+        }
+        world.resource_scope(|world, mut params: Mut<WorldInspectorParams>| {
+            f(world, params.bypass_change_detection())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        any::TypeId,
+        path::{Path, PathBuf},
+    };
+
+    use bevy_core::Name;
+    use bevy_reflect::{TypePath, TypeRegistry};
+    use bevy_transform::components::Transform;
+
+    use super::WorldInspectorParams;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "bevy-inspector-egui-{}-{name}.json",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn type_registry() -> TypeRegistry {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Name>();
+        type_registry.register::<Transform>();
+        type_registry
+    }
+
+    fn params_with_save_path(path: &Path) -> WorldInspectorParams {
+        WorldInspectorParams {
+            save_path: Some(path.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn ignore_list_round_trip() {
+        let path = temp_path("round-trip");
+        let type_registry = type_registry();
+
+        let mut params = params_with_save_path(&path);
+        params.sync_ignore_list(&type_registry);
+        assert!(params.ignore_components.is_empty());
+        params.ignore_component::<Transform>();
+        params.sync_ignore_list(&type_registry);
+
+        let saved: Vec<String> =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved, [Transform::type_path()]);
+
+        let mut params = params_with_save_path(&path);
+        params.sync_ignore_list(&type_registry);
+        assert_eq!(
+            params.ignore_components,
+            [TypeId::of::<Transform>()].into_iter().collect()
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn ignore_list_keeps_unresolved_type_paths() {
+        let path = temp_path("unresolved");
+        let type_registry = type_registry();
+        std::fs::write(&path, r#"["my_game::Removed"]"#).unwrap();
+
+        let mut params = params_with_save_path(&path);
+        params.sync_ignore_list(&type_registry);
+        assert!(params.ignore_components.is_empty());
+        params.ignore_component::<Name>();
+        params.sync_ignore_list(&type_registry);
+
+        let saved: Vec<String> =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved, [Name::type_path(), "my_game::Removed"]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn malformed_ignore_list_is_ignored() {
+        let path = temp_path("malformed");
+        let type_registry = type_registry();
+        std::fs::write(&path, "{ not json").unwrap();
+
+        let mut params = params_with_save_path(&path);
+        params.ignore_component::<Name>();
+        params.sync_ignore_list(&type_registry);
+        assert_eq!(
+            params.ignore_components,
+            [TypeId::of::<Name>()].into_iter().collect()
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{ not json");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
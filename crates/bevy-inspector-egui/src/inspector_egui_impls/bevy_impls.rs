@@ -2,12 +2,19 @@ use bevy_asset::{Assets, Handle};
 use bevy_ecs::world::World;
 use bevy_ecs::{entity::Entity, system::CommandQueue};
 use bevy_render::mesh::Mesh;
-use bevy_render::{color::Color, view::RenderLayers};
-use egui::{ecolor::Hsva, Color32};
+use bevy_render::{
+    color::Color,
+    view::{InheritedVisibility, RenderLayers, ViewVisibility, Visibility},
+};
+use bevy_time::Timer;
+use egui::ecolor::Hsva;
 use std::any::Any;
 
 use crate::{
-    bevy_inspector::errors::{dead_asset_handle, no_world_in_context, show_error},
+    bevy_inspector::{
+        errors::{dead_asset_handle, no_world_in_context, show_error},
+        InspectorSelection,
+    },
     egui_utils,
     inspector_options::std_options::{EntityDisplay, EntityOptions},
     reflect_inspector::{Context, InspectorUi},
@@ -31,13 +38,13 @@ impl InspectorPrimitive for Entity {
             .unwrap_or_default();
 
         match options.display {
-            EntityDisplay::Id => {
-                ui.label(format!("{entity:?}"));
-            }
+            EntityDisplay::Id => entity_link_ui(ui, entity, &mut env),
             EntityDisplay::Components => {
                 let Context {
                     world: Some(world),
                     queue,
+                    world_inspector_params,
+                    ..
                 } = &mut env.context
                 else {
                     no_world_in_context(ui, "Entity");
@@ -49,7 +56,18 @@ impl InspectorPrimitive for Entity {
                 egui::CollapsingHeader::new(entity_name)
                     .id_source(id)
                     .show(ui, |ui| {
+                        if world.contains_entity(entity) {
+                            if let Some(queue) = queue.as_deref_mut() {
+                                if ui.link("🔗 Show in world inspector").clicked() {
+                                    select_entity(queue, entity);
+                                }
+                            }
+                        }
                         let _queue = CommandQueue::default();
+                        let mut params = world_inspector_params.map_or_else(
+                            crate::bevy_inspector::WorldInspectorParams::default,
+                            crate::bevy_inspector::WorldInspectorParams::clone_config,
+                        );
                         crate::bevy_inspector::ui_for_entity_components(
                             world,
                             queue.as_deref_mut(),
@@ -57,6 +75,7 @@ impl InspectorPrimitive for Entity {
                             ui,
                             id,
                             env.type_registry,
+                            &mut params,
                         );
                         if options.despawnable && world.contains_entity(entity) {
                             if let Some(queue) = queue {
@@ -73,9 +92,50 @@ impl InspectorPrimitive for Entity {
         false
     }
 
-    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
-        ui.label(format!("{self:?}"));
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        _: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) {
+        entity_link_ui(ui, *self, &mut env);
+    }
+}
+
+/// Shows the name of the entity as a link which selects it in the world inspector, or just its id without a world
+fn entity_link_ui(ui: &mut egui::Ui, entity: Entity, env: &mut InspectorUi<'_, '_>) {
+    let Context {
+        world: Some(world),
+        queue: Some(queue),
+        ..
+    } = &mut env.context
+    else {
+        ui.label(format!("{entity:?}"));
+        return;
+    };
+    if !world.contains_entity(entity) {
+        ui.label(format!("{entity:?}"));
+        return;
     }
+
+    let entity_name = crate::utils::guess_entity_name::guess_entity_name_restricted(world, entity);
+    if ui
+        .link(entity_name)
+        .on_hover_text("Show in world inspector")
+        .clicked()
+    {
+        select_entity(queue, entity);
+    }
+}
+
+/// Sets the [`InspectorSelection::selected`] entity, which the world inspector scrolls to
+fn select_entity(queue: &mut CommandQueue, entity: Entity) {
+    queue.push(move |world: &mut World| {
+        world
+            .get_resource_or_insert_with(InspectorSelection::default)
+            .selected = Some(entity);
+    });
 }
 
 impl InspectorPrimitive for Handle<Mesh> {
@@ -183,72 +243,43 @@ fn mesh_ui_inner(mesh: &Mesh, ui: &mut egui::Ui) {
 }
 
 impl InspectorPrimitive for Color {
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
-        match self {
-            Color::Rgba {
-                red,
-                green,
-                blue,
-                alpha,
-            } => {
-                let mut color = Color32::from_rgba_premultiplied(
-                    (*red * 255.) as u8,
-                    (*green * 255.) as u8,
-                    (*blue * 255.) as u8,
-                    (*alpha * 255.) as u8,
-                );
-                if ui.color_edit_button_srgba(&mut color).changed() {
-                    let [r, g, b, a] = color.to_array();
-                    *red = r as f32 / 255.;
-                    *green = g as f32 / 255.;
-                    *blue = b as f32 / 255.;
-                    *alpha = a as f32 / 255.;
-                    return true;
-                }
-            }
-            Color::RgbaLinear {
-                red,
-                green,
-                blue,
-                alpha,
-            } => {
-                let mut color = [*red, *green, *blue, *alpha];
-                if ui
-                    .color_edit_button_rgba_premultiplied(&mut color)
-                    .changed()
-                {
-                    *red = color[0];
-                    *green = color[1];
-                    *blue = color[2];
-                    *alpha = color[3];
-                    return true;
-                }
-            }
-            Color::Hsla {
-                hue,
-                saturation,
-                lightness,
-                alpha,
-            } => {
-                let mut hsva = Hsva::new(*hue, *saturation, *lightness, *alpha);
-                if ui.color_edit_button_hsva(&mut hsva).changed() {
-                    *hue = hsva.h;
-                    *saturation = hsva.s;
-                    *lightness = hsva.v;
-                    *alpha = hsva.a;
-                    return true;
-                }
-            }
-            Color::Lcha { .. } => {
-                let [hue, saturation, lightness, alpha] = self.as_hsla_f32();
-                let mut hsva = Hsva::new(hue, saturation, lightness, alpha);
-                if ui.color_edit_button_hsva(&mut hsva).changed() {
-                    *self = Color::hsla(hue, saturation, lightness, alpha).as_lcha();
-                    return true;
-                }
-            }
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        // edit all color spaces in linear rgb, so that the picker matches the rendered color
+        let current = self.as_linear_rgba_f32();
+        let [red, green, blue, alpha] = current;
+
+        // keep the hue around when editing colors without saturation, together with the color it was stored for.
+        // Comparing with a conversion of the hue back to rgb would detect changes after every edit, because of rounding errors.
+        let (mut hsva, stored_for) = ui.memory_mut(|memory| {
+            *memory.data.get_temp_mut_or_insert_with(id, || {
+                (
+                    Hsva::from_rgba_unmultiplied(red, green, blue, alpha),
+                    current,
+                )
+            })
+        });
+        let externally_changed = stored_for != current;
+        if externally_changed {
+            hsva = Hsva::from_rgba_unmultiplied(red, green, blue, alpha);
         }
-        false
+
+        let changed = ui.color_edit_button_hsva(&mut hsva).changed();
+        if changed {
+            let [red, green, blue, alpha] = hsva.to_rgba_unmultiplied();
+            let linear = Color::rgba_linear(red, green, blue, alpha);
+            *self = match self {
+                Color::Rgba { .. } => linear.as_rgba(),
+                Color::RgbaLinear { .. } => linear,
+                Color::Hsla { .. } => linear.as_hsla(),
+                Color::Lcha { .. } => linear.as_lcha(),
+            };
+        }
+        if changed || externally_changed {
+            let stored_for = self.as_linear_rgba_f32();
+            ui.memory_mut(|memory| memory.data.insert_temp(id, (hsva, stored_for)));
+        }
+
+        changed
     }
 
     fn ui_readonly(
@@ -263,6 +294,68 @@ impl InspectorPrimitive for Color {
     }
 }
 
+impl InspectorPrimitive for Visibility {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            for (visibility, text, hover_text) in [
+                (
+                    Visibility::Inherited,
+                    "Inherited",
+                    "Visible if the parent is visible",
+                ),
+                (
+                    Visibility::Visible,
+                    "Visible",
+                    "Visible even if the parent is hidden",
+                ),
+                (
+                    Visibility::Hidden,
+                    "Hidden",
+                    "Hidden together with the children",
+                ),
+            ] {
+                changed |= ui
+                    .selectable_value(self, visibility, text)
+                    .on_hover_text(hover_text)
+                    .changed();
+            }
+        });
+        changed
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
+        ui.label(format!("{self:?}"));
+    }
+}
+
+/// The computed visibility components are written by bevy every frame, so they are always displayed read-only
+fn computed_visibility_ui(ui: &mut egui::Ui, visible: bool) {
+    ui.label(if visible { "Visible" } else { "Hidden" });
+}
+
+impl InspectorPrimitive for InheritedVisibility {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        computed_visibility_ui(ui, self.get());
+        false
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
+        computed_visibility_ui(ui, self.get());
+    }
+}
+
+impl InspectorPrimitive for ViewVisibility {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        computed_visibility_ui(ui, self.get());
+        false
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
+        computed_visibility_ui(ui, self.get());
+    }
+}
+
 impl InspectorPrimitive for RenderLayers {
     fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) -> bool {
         let mut new_value = None;
@@ -307,3 +400,86 @@ impl InspectorPrimitive for RenderLayers {
         }
     }
 }
+
+impl InspectorPrimitive for Timer {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        timer_progress_ui(self, ui);
+
+        let mut changed = false;
+        egui::Grid::new(id).show(ui, |ui| {
+            ui.label("duration");
+            let mut duration = self.duration();
+            if env.ui_for_reflect_with_options(&mut duration, ui, id.with("duration"), &()) {
+                self.set_duration(duration);
+                changed = true;
+            }
+            ui.end_row();
+
+            ui.label("mode");
+            let mut mode = self.mode();
+            if env.ui_for_reflect_with_options(&mut mode, ui, id.with("mode"), &()) {
+                self.set_mode(mode);
+                changed = true;
+            }
+            ui.end_row();
+
+            ui.label("paused");
+            let mut paused = self.paused();
+            if ui.checkbox(&mut paused, "").changed() {
+                if paused {
+                    self.pause();
+                } else {
+                    self.unpause();
+                }
+                changed = true;
+            }
+            ui.end_row();
+        });
+        changed
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) {
+        timer_progress_ui(self, ui);
+
+        egui::Grid::new(id).show(ui, |ui| {
+            ui.label("duration");
+            env.ui_for_reflect_readonly_with_options(
+                &self.duration(),
+                ui,
+                id.with("duration"),
+                &(),
+            );
+            ui.end_row();
+
+            ui.label("mode");
+            env.ui_for_reflect_readonly_with_options(&self.mode(), ui, id.with("mode"), &());
+            ui.end_row();
+
+            ui.label("paused");
+            ui.add_enabled(false, egui::Checkbox::without_text(&mut self.paused()));
+            ui.end_row();
+        });
+    }
+}
+
+/// Shows the elapsed time of the timer as a progress bar
+fn timer_progress_ui(timer: &Timer, ui: &mut egui::Ui) {
+    let text = format!(
+        "{:.2}s / {:.2}s",
+        timer.elapsed_secs(),
+        timer.duration().as_secs_f32()
+    );
+    ui.add(egui::ProgressBar::new(timer.fraction()).text(text));
+}
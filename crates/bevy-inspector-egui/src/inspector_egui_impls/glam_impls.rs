@@ -6,6 +6,12 @@ use bevy_reflect::Reflect;
 use crate::inspector_options::std_options::NumberOptions;
 use crate::reflect_inspector::InspectorUi;
 
+/// The layout of the columns of vectors, which stretches the component widgets to the width of their column,
+/// so that they grow with the window instead of clipping their values
+fn column_layout() -> egui::Layout {
+    egui::Layout::top_down_justified(egui::Align::Min)
+}
+
 macro_rules! vec_ui_many {
     ($name_many:ident $ty:ty>$elem_ty:ty: $count:literal $($component:ident)*) => {
         pub fn $name_many(
@@ -29,7 +35,7 @@ macro_rules! vec_ui_many {
                             }));
 
                             let id = id.with(stringify!($component));
-                            changed |= crate::inspector_egui_impls::change_slider($component, id, same, |change, overwrite| {
+                            changed |= $component.with_layout(column_layout(), |ui| crate::inspector_egui_impls::change_slider(ui, id, same, |change, overwrite| {
                                 for value in values.iter_mut() {
                                     let value = projector(*value);
                                     let value = value.downcast_mut::<$ty>().unwrap();
@@ -42,7 +48,7 @@ macro_rules! vec_ui_many {
                                     }
 
                                 }
-                            });
+                            })).inner;
                         )*
                     }
                     _ => unreachable!(),
@@ -75,7 +81,9 @@ macro_rules! vec_ui {
 
                 ui.columns($count, |ui| match ui {
                     [$($component),*] => {
-                        $(changed |= env.ui_for_reflect_with_options(&mut value.$component, $component, id.with(stringify!($component)), &options.map(|vec| vec.$component));)*
+                        $(changed |= $component.with_layout(column_layout(), |ui| {
+                            env.ui_for_reflect_with_options(&mut value.$component, ui, id.with(stringify!($component)), &options.map(|vec| vec.$component))
+                        }).inner;)*
                     }
                     _ => unreachable!(),
                 });
@@ -97,7 +105,7 @@ macro_rules! vec_ui {
 
                 ui.columns($count, |ui| match ui {
                     [$($component),*] => {
-                        $(env.ui_for_reflect_readonly(&value.$component, $component);)*
+                        $($component.with_layout(column_layout(), |ui| env.ui_for_reflect_readonly(&value.$component, ui));)*
                     }
                     _ => unreachable!(),
                 });
@@ -106,20 +114,31 @@ macro_rules! vec_ui {
     };
 }
 
+/// Shows the matrix as a grid of its elements, with the columns of the matrix displayed as columns
 macro_rules! mat_ui {
-    ($name:ident $name_readonly:ident $ty:ty: $($component:ident)*) => {
+    ($name:ident $name_readonly:ident $ty:ty: $count:literal) => {
         pub fn $name(
             value: &mut dyn Any,
             ui: &mut egui::Ui,
             _: &dyn Any,
-            _: egui::Id,
+            id: egui::Id,
             mut env: InspectorUi<'_, '_>,
         ) -> bool {
             let value = value.downcast_mut::<$ty>().unwrap();
 
             let mut changed = false;
-            ui.vertical(|ui| {
-                $(changed |= env.ui_for_reflect(&mut value.$component, ui);)*
+            egui::Grid::new(id).show(ui, |ui| {
+                for row in 0..$count {
+                    for col in 0..$count {
+                        changed |= env.ui_for_reflect_with_options(
+                            &mut value.col_mut(col)[row],
+                            ui,
+                            id.with((row, col)),
+                            &(),
+                        );
+                    }
+                    ui.end_row();
+                }
             });
             changed
         }
@@ -128,13 +147,23 @@ macro_rules! mat_ui {
             value: &dyn Any,
             ui: &mut egui::Ui,
             _: &dyn Any,
-            _: egui::Id,
+            id: egui::Id,
             mut env: InspectorUi<'_, '_>,
         ) {
             let value = value.downcast_ref::<$ty>().unwrap();
 
-            ui.vertical(|ui| {
-                $(env.ui_for_reflect_readonly(&value.$component, ui);)*
+            egui::Grid::new(id).show(ui, |ui| {
+                for row in 0..$count {
+                    for col in 0..$count {
+                        env.ui_for_reflect_readonly_with_options(
+                            &value.col(col)[row],
+                            ui,
+                            id.with((row, col)),
+                            &(),
+                        );
+                    }
+                    ui.end_row();
+                }
             });
         }
     };
@@ -170,13 +199,13 @@ vec_ui_many!(dvec2_ui_many DVec2>f64: 2 x y);
 vec_ui_many!(dvec3_ui_many DVec3>f64: 3 x y z);
 vec_ui_many!(dvec4_ui_many DVec4>f64: 4 x y z w);
 
-mat_ui!(mat2_ui mat2_ui_readonly Mat2: x_axis y_axis);
-mat_ui!(mat3_ui mat3_ui_readonly Mat3: x_axis y_axis z_axis);
-mat_ui!(mat3a_ui mat3a_ui_readonly Mat3A: x_axis y_axis z_axis);
-mat_ui!(mat4_ui mat4_ui_readonly Mat4: x_axis y_axis z_axis w_axis);
-mat_ui!(dmat2_ui dmat2_ui_readonly DMat2: x_axis y_axis);
-mat_ui!(dmat3_ui dmat3_ui_readonly DMat3: x_axis y_axis z_axis);
-mat_ui!(dmat4_ui dmat4_ui_readonly DMat4: x_axis y_axis z_axis w_axis);
+mat_ui!(mat2_ui mat2_ui_readonly Mat2: 2);
+mat_ui!(mat3_ui mat3_ui_readonly Mat3: 3);
+mat_ui!(mat3a_ui mat3a_ui_readonly Mat3A: 3);
+mat_ui!(mat4_ui mat4_ui_readonly Mat4: 4);
+mat_ui!(dmat2_ui dmat2_ui_readonly DMat2: 2);
+mat_ui!(dmat3_ui dmat3_ui_readonly DMat3: 3);
+mat_ui!(dmat4_ui dmat4_ui_readonly DMat4: 4);
 
 pub mod quat {
     use std::any::Any;
@@ -212,8 +241,17 @@ pub mod quat {
             Quat::from_euler(EulerRot::XYZ, self.0.x, self.0.y, self.0.z)
         }
 
-        fn ui(&mut self, ui: &mut egui::Ui, mut env: InspectorUi<'_, '_>) -> bool {
-            env.ui_for_reflect(&mut self.0, ui)
+        fn ui(&mut self, ui: &mut egui::Ui, _env: InspectorUi<'_, '_>) -> bool {
+            let Vec3 { x, y, z } = &mut self.0;
+
+            let mut changed = false;
+            ui.horizontal(|ui| {
+                for (label, angle) in [("x", x), ("y", y), ("z", z)] {
+                    ui.label(label);
+                    changed |= ui.drag_angle(angle).changed();
+                }
+            });
+            changed
         }
     }
 
@@ -284,24 +322,27 @@ pub mod quat {
     fn quat_ui_kind<T: Send + Sync + 'static + Copy + RotationEdit>(
         val: &mut Quat,
         ui: &mut egui::Ui,
+        id: egui::Id,
         env: InspectorUi<'_, '_>,
     ) -> bool {
-        let id = ui.id();
         let mut intermediate = ui.memory_mut(|memory| {
             *memory
                 .data
                 .get_temp_mut_or_insert_with(id, || T::from_quat(*val))
         });
 
-        let externally_changed = !intermediate.to_quat().abs_diff_eq(*val, std::f32::EPSILON);
+        let externally_changed = !intermediate.to_quat().abs_diff_eq(*val, f32::EPSILON);
         if externally_changed {
             intermediate = T::from_quat(*val);
         }
 
         let changed = intermediate.ui(ui, env);
 
-        if changed || externally_changed {
+        // only write back edits, so that the value doesn't drift by round-tripping through the intermediate representation
+        if changed {
             *val = intermediate.to_quat();
+        }
+        if changed || externally_changed {
             ui.memory_mut(|memory| memory.data.insert_temp(id, intermediate));
         }
 
@@ -312,7 +353,7 @@ pub mod quat {
         value: &mut dyn Any,
         ui: &mut egui::Ui,
         options: &dyn Any,
-        _: egui::Id,
+        id: egui::Id,
         mut env: InspectorUi<'_, '_>,
     ) -> bool {
         let value = value.downcast_mut::<Quat>().unwrap();
@@ -331,9 +372,9 @@ pub mod quat {
                 }
                 changed
             }
-            QuatDisplay::Euler => quat_ui_kind::<Euler>(value, ui, env),
-            QuatDisplay::YawPitchRoll => quat_ui_kind::<YawPitchRoll>(value, ui, env),
-            QuatDisplay::AxisAngle => quat_ui_kind::<AxisAngle>(value, ui, env),
+            QuatDisplay::Euler => quat_ui_kind::<Euler>(value, ui, id, env),
+            QuatDisplay::YawPitchRoll => quat_ui_kind::<YawPitchRoll>(value, ui, id, env),
+            QuatDisplay::AxisAngle => quat_ui_kind::<AxisAngle>(value, ui, id, env),
         })
         .inner
     }
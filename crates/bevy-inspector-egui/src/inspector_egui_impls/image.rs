@@ -10,12 +10,12 @@ use bevy_reflect::DynamicTypePath;
 use bevy_render::texture::Image;
 use egui::load::SizedTexture;
 use once_cell::sync::Lazy;
-use pretty_type_name::pretty_type_name;
 
 use crate::{
     bevy_inspector::errors::{no_world_in_context, show_error},
     reflect_inspector::InspectorUi,
     restricted_world_view::RestrictedWorldView,
+    utils::pretty_type_name::pretty_type_name,
 };
 
 use super::InspectorPrimitive;
@@ -64,8 +64,9 @@ impl InspectorPrimitive for Handle<Image> {
         let mut selected_path = None;
         let mut image_picker_search_text = String::from("");
         ui.data_mut(|data| {
-            image_picker_search_text.clone_from(data
-                .get_temp_mut_or_default::<String>(id.with("image_picker_search_text")));
+            image_picker_search_text.clone_from(
+                data.get_temp_mut_or_default::<String>(id.with("image_picker_search_text")),
+            );
         });
 
         // build and show the dropdown
@@ -7,6 +7,7 @@ use std::{
     any::{Any, TypeId},
     borrow::Cow,
     path::PathBuf,
+    sync::Arc,
 };
 
 mod bevy_impls;
@@ -26,9 +27,25 @@ type InspectorEguiImplFnMany = for<'a> fn(
     &mut [&mut dyn Reflect],
     &dyn Fn(&mut dyn Reflect) -> &mut dyn Reflect,
 ) -> bool;
+type InspectorEguiImplBoxed = Arc<
+    dyn Fn(&mut dyn Any, &mut egui::Ui, &dyn Any, egui::Id, InspectorUi<'_, '_>) -> bool
+        + Send
+        + Sync,
+>;
+type InspectorEguiImplBoxedReadonly =
+    Arc<dyn Fn(&dyn Any, &mut egui::Ui, &dyn Any, egui::Id, InspectorUi<'_, '_>) + Send + Sync>;
 
 /// Custom UI implementation for a concrete type.
 ///
+/// Register the implementation as [`InspectorEguiImpl`] type data of `T`, like in the example below.
+/// The [`InspectorUi`] checks for that type data before anything else, so it takes precedence over the
+/// reflection-based UI of structs, enums and lists, and over the short circuit for bevy types like `Handle<T>`.
+/// The `options` are the [`InspectorOptions`](struct@crate::inspector_options::InspectorOptions) of the field,
+/// or the [`ReflectInspectorOptions`](crate::inspector_options::ReflectInspectorOptions) registered for `T`,
+/// and `env` can be used to display nested values.
+///
+/// For one-off UIs which don't need options, [`register_with`] accepts a closure instead.
+///
 /// # Example Usage
 /// ```rust,no_run
 /// use bevy::prelude::*;
@@ -64,6 +81,7 @@ type InspectorEguiImplFnMany = for<'a> fn(
 /// }
 /// ```
 pub trait InspectorPrimitive: Reflect {
+    /// Display the value and return whether it was changed
     fn ui(
         &mut self,
         ui: &mut egui::Ui,
@@ -71,6 +89,7 @@ pub trait InspectorPrimitive: Reflect {
         id: egui::Id,
         env: InspectorUi<'_, '_>,
     ) -> bool;
+    /// Display the value without allowing edits
     fn ui_readonly(
         &self,
         ui: &mut egui::Ui,
@@ -133,8 +152,8 @@ fn ui_readonly_vtable<T: InspectorPrimitive>(
 /// to display a certain type. You can use [`InspectorPrimitive`] to avoid manually writing the function pointers with correct downcasting.
 #[derive(Clone)]
 pub struct InspectorEguiImpl {
-    fn_mut: InspectorEguiImplFn,
-    fn_readonly: InspectorEguiImplFnReadonly,
+    fn_mut: InspectorEguiImplBoxed,
+    fn_readonly: InspectorEguiImplBoxedReadonly,
     fn_many: InspectorEguiImplFnMany,
 }
 
@@ -146,18 +165,10 @@ impl<T: InspectorPrimitive> FromType<T> for InspectorEguiImpl {
 
 impl InspectorEguiImpl {
     pub fn of<T: InspectorPrimitive + PartialEq + Clone + Default>() -> Self {
-        InspectorEguiImpl {
-            fn_mut: ui_vtable::<T>,
-            fn_readonly: ui_readonly_vtable::<T>,
-            fn_many: ui_many_vtable::<T>,
-        }
+        InspectorEguiImpl::new(ui_vtable::<T>, ui_readonly_vtable::<T>, ui_many_vtable::<T>)
     }
     pub fn of_with_many<T: InspectorPrimitive>(fn_many: InspectorEguiImplFnMany) -> Self {
-        InspectorEguiImpl {
-            fn_mut: ui_vtable::<T>,
-            fn_readonly: ui_readonly_vtable::<T>,
-            fn_many,
-        }
+        InspectorEguiImpl::new(ui_vtable::<T>, ui_readonly_vtable::<T>, fn_many)
     }
 
     /// Create a new [`InspectorEguiImpl`] from functions displaying a type
@@ -167,12 +178,33 @@ impl InspectorEguiImpl {
         fn_many: InspectorEguiImplFnMany,
     ) -> Self {
         InspectorEguiImpl {
-            fn_mut,
-            fn_readonly,
+            fn_mut: Arc::new(fn_mut),
+            fn_readonly: Arc::new(fn_readonly),
             fn_many,
         }
     }
 
+    /// Create a new [`InspectorEguiImpl`] from a closure, which returns whether the value was changed.
+    ///
+    /// The readonly UI calls the closure on a copy of the value inside a disabled [`egui::Ui`].
+    /// Editing multiple values at once is not supported.
+    ///
+    /// See [`register_with`] for registering the closure for a type.
+    pub fn from_fn<T: Any + Clone>(
+        f: impl Fn(&mut T, &mut egui::Ui) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let f = Arc::new(f);
+        let f_readonly = Arc::clone(&f);
+        InspectorEguiImpl {
+            fn_mut: Arc::new(move |value, ui, _, _, _| f(value.downcast_mut::<T>().unwrap(), ui)),
+            fn_readonly: Arc::new(move |value, ui, _, _, _| {
+                let mut copy = value.downcast_ref::<T>().unwrap().clone();
+                ui.add_enabled_ui(false, |ui| f_readonly(&mut copy, ui));
+            }),
+            fn_many: many_unimplemented::<T>,
+        }
+    }
+
     pub fn execute<'a, 'c: 'a>(
         &'a self,
         value: &mut dyn Any,
@@ -206,6 +238,38 @@ impl InspectorEguiImpl {
     }
 }
 
+/// Display values of type `T` using the closure `f`, which returns whether the value was changed.
+///
+/// # Panics
+///
+/// Panics if `T` isn't registered in the type registry yet, for example using [`App::register_type`](bevy_app::App::register_type).
+///
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::inspector_egui_impls::register_with;
+///
+/// #[derive(Reflect, Clone)]
+/// struct Health(f32);
+///
+/// fn main() {
+///     let mut app = App::new();
+///     app.register_type::<Health>();
+///     register_with::<Health>(
+///         &mut app.world.resource::<AppTypeRegistry>().write(),
+///         |health, ui| ui.add(egui::Slider::new(&mut health.0, 0.0..=100.0)).changed(),
+///     );
+/// }
+/// ```
+pub fn register_with<T: Any + Clone>(
+    type_registry: &mut TypeRegistry,
+    f: impl Fn(&mut T, &mut egui::Ui) -> bool + Send + Sync + 'static,
+) {
+    type_registry
+        .get_mut(TypeId::of::<T>())
+        .unwrap_or_else(|| panic!("{} not registered", std::any::type_name::<T>()))
+        .insert(InspectorEguiImpl::from_fn(f));
+}
+
 fn many_unimplemented<T: Any>(
     ui: &mut egui::Ui,
     _options: &dyn Any,
@@ -214,7 +278,7 @@ fn many_unimplemented<T: Any>(
     _values: &mut [&mut dyn Reflect],
     _projector: &dyn Fn(&mut dyn Reflect) -> &mut dyn Reflect,
 ) -> bool {
-    no_multiedit(ui, &pretty_type_name::pretty_type_name::<T>());
+    no_multiedit(ui, &crate::utils::pretty_type_name::pretty_type_name::<T>());
     false
 }
 
@@ -223,6 +287,13 @@ fn add<T: InspectorPrimitive + TypePath + PartialEq + Clone + Default>(
 ) {
     type_registry.register_type_data::<T, InspectorEguiImpl>();
 }
+/// The `NonZero*` integers have no [`Default`] and aren't registered by bevy
+fn add_non_zero<T: InspectorPrimitive + TypePath + bevy_reflect::GetTypeRegistration>(
+    type_registry: &mut TypeRegistry,
+) {
+    type_registry.register::<T>();
+    type_registry.register_type_data::<T, InspectorEguiImpl>();
+}
 fn add_of_with_many<T: InspectorPrimitive>(
     type_registry: &mut TypeRegistry,
     fn_many: InspectorEguiImplFnMany,
@@ -270,6 +341,17 @@ pub fn register_std_impls(type_registry: &mut TypeRegistry) {
     add::<std::ops::Range<f64>>(type_registry);
 
     add::<std::time::Duration>(type_registry);
+
+    add_non_zero::<std::num::NonZeroU8>(type_registry);
+    add_non_zero::<std::num::NonZeroU16>(type_registry);
+    add_non_zero::<std::num::NonZeroU32>(type_registry);
+    add_non_zero::<std::num::NonZeroU64>(type_registry);
+    add_non_zero::<std::num::NonZeroUsize>(type_registry);
+    add_non_zero::<std::num::NonZeroI8>(type_registry);
+    add_non_zero::<std::num::NonZeroI16>(type_registry);
+    add_non_zero::<std::num::NonZeroI32>(type_registry);
+    add_non_zero::<std::num::NonZeroI64>(type_registry);
+    add_non_zero::<std::num::NonZeroIsize>(type_registry);
     add_of_with_many::<Instant>(type_registry, many_unimplemented::<Instant>);
 }
 
@@ -311,6 +393,13 @@ pub fn register_bevy_impls(type_registry: &mut TypeRegistry) {
     add_of_with_many::<bevy_ecs::entity::Entity>(type_registry, many_unimplemented::<bevy_ecs::entity::Entity>);
     add::<bevy_render::color::Color>(type_registry);
     add::<bevy_render::view::RenderLayers>(type_registry);
+    add::<bevy_render::view::Visibility>(type_registry);
+    add::<bevy_render::view::InheritedVisibility>(type_registry);
+    add::<bevy_render::view::ViewVisibility>(type_registry);
+
+    type_registry.register::<bevy_time::Timer>();
+    type_registry.register::<bevy_time::TimerMode>();
+    add::<bevy_time::Timer>(type_registry);
 }
 
 pub(crate) fn change_slider<T>(
@@ -1,13 +1,24 @@
-use std::{borrow::Cow, ops::AddAssign, path::PathBuf};
+use std::{
+    borrow::Cow,
+    num::{
+        NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+        NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    ops::{AddAssign, RangeInclusive},
+    path::PathBuf,
+};
 
 use bevy_reflect::{Reflect, TypePath};
 use bevy_utils::Instant;
 use egui::{DragValue, RichText, TextBuffer};
 
 use super::{change_slider, iter_all_eq, InspectorPrimitive, InspectorUi};
-use crate::inspector_options::{
-    std_options::{NumberDisplay, NumberOptions, RangeOptions},
-    InspectorOptionsType,
+use crate::{
+    inspector_options::{
+        std_options::{NumberDisplay, NumberOptions, RangeOptions},
+        InspectorOptionsType,
+    },
+    reflect_inspector::NumberDefaults,
 };
 use std::{any::Any, time::Duration};
 
@@ -30,13 +41,13 @@ impl<T: Reflect + Num> InspectorPrimitive for T {
         ui: &mut egui::Ui,
         options: &dyn Any,
         _: egui::Id,
-        _: InspectorUi<'_, '_>,
+        env: InspectorUi<'_, '_>,
     ) -> bool {
         let options = options
             .downcast_ref::<NumberOptions<T>>()
             .cloned()
             .unwrap_or_default();
-        display_number(self, &options, ui, 0.1)
+        display_number(self, &options, ui, env.context.number_defaults)
     }
 
     fn ui_readonly(
@@ -44,13 +55,13 @@ impl<T: Reflect + Num> InspectorPrimitive for T {
         ui: &mut egui::Ui,
         options: &dyn Any,
         _: egui::Id,
-        _: InspectorUi<'_, '_>,
+        env: InspectorUi<'_, '_>,
     ) {
         let options = options
             .downcast_ref::<NumberOptions<T>>()
             .cloned()
             .unwrap_or_default();
-        let decimal_range = 0..=1usize;
+        let decimal_range = readonly_decimal_range(env.context.number_defaults);
         ui.add(
             egui::Button::new(
                 RichText::new(format!(
@@ -72,28 +83,28 @@ pub fn number_ui<T: egui::emath::Numeric>(
     ui: &mut egui::Ui,
     options: &dyn Any,
     _: egui::Id,
-    _: InspectorUi<'_, '_>,
+    env: InspectorUi<'_, '_>,
 ) -> bool {
     let value = value.downcast_mut::<T>().unwrap();
     let options = options
         .downcast_ref::<NumberOptions<T>>()
         .cloned()
         .unwrap_or_default();
-    display_number(value, &options, ui, 0.1)
+    display_number(value, &options, ui, env.context.number_defaults)
 }
 pub fn number_ui_readonly<T: egui::emath::Numeric>(
     value: &dyn Any,
     ui: &mut egui::Ui,
     options: &dyn Any,
     _: egui::Id,
-    _: InspectorUi<'_, '_>,
+    env: InspectorUi<'_, '_>,
 ) {
     let value = value.downcast_ref::<T>().unwrap();
     let options = options
         .downcast_ref::<NumberOptions<T>>()
         .cloned()
         .unwrap_or_default();
-    let decimal_range = 0..=1usize;
+    let decimal_range = readonly_decimal_range(env.context.number_defaults);
     ui.add(
         egui::Button::new(
             RichText::new(format!(
@@ -109,15 +120,24 @@ pub fn number_ui_readonly<T: egui::emath::Numeric>(
     );
 }
 
+fn readonly_decimal_range(defaults: NumberDefaults) -> RangeInclusive<usize> {
+    match defaults.decimals {
+        Some(decimals) => decimals..=decimals,
+        None => 0..=1,
+    }
+}
+
 fn display_number<T: egui::emath::Numeric>(
     value: &mut T,
     options: &NumberOptions<T>,
     ui: &mut egui::Ui,
-    default_speed: f32,
+    defaults: NumberDefaults,
 ) -> bool {
-    let mut changed = match options.display {
+    // egui clamps the value it displays, so edit a copy to keep out of range values until they are edited
+    let mut edited = *value;
+    let changed = match options.display {
         NumberDisplay::Drag => {
-            let mut widget = egui::DragValue::new(value);
+            let mut widget = egui::DragValue::new(&mut edited);
             if !options.prefix.is_empty() {
                 widget = widget.prefix(&options.prefix);
             }
@@ -133,36 +153,69 @@ fn display_number<T: egui::emath::Numeric>(
             if options.speed != 0.0 {
                 widget = widget.speed(options.speed);
             } else {
-                widget = widget.speed(default_speed);
+                widget = widget.speed(defaults.drag_speed);
+            }
+            if let Some(decimals) = defaults.decimals {
+                widget = widget.fixed_decimals(decimals);
+            }
+            if defaults.allow_expressions {
+                widget = widget.custom_parser(crate::utils::expression::evaluate);
+                let response = ui.add(widget);
+                expression_error_ui(ui, &response);
+                response.changed()
+            } else {
+                ui.add(widget).changed()
             }
-            ui.add(widget).changed()
         }
         NumberDisplay::Slider => {
             let min = options.min.unwrap_or_else(|| T::from_f64(0.0));
             let max = options.max.unwrap_or_else(|| T::from_f64(1.0));
             let range = min..=max;
-            let widget = egui::Slider::new(value, range);
+            let mut widget = egui::Slider::new(&mut edited, range);
+            if let Some(decimals) = defaults.decimals {
+                widget = widget.fixed_decimals(decimals);
+            }
             ui.add(widget).changed()
         }
     };
 
+    if !changed {
+        return false;
+    }
     if let Some(min) = options.min {
-        let as_f64 = value.to_f64();
+        let as_f64 = edited.to_f64();
         let min = min.to_f64();
         if as_f64 < min {
-            *value = T::from_f64(min);
-            changed = true;
+            edited = T::from_f64(min);
         }
     }
     if let Some(max) = options.max {
-        let as_f64 = value.to_f64();
+        let as_f64 = edited.to_f64();
         let max = max.to_f64();
         if as_f64 > max {
-            *value = T::from_f64(max);
-            changed = true;
+            edited = T::from_f64(max);
         }
     }
-    changed
+    *value = edited;
+    true
+}
+
+/// Outlines the number field while the expression typed into it can't be evaluated
+fn expression_error_ui(ui: &egui::Ui, response: &egui::Response) {
+    if !response.has_focus() {
+        return;
+    }
+    // the `DragValue` stores the text being edited under its id
+    let Some(text) = ui.data(|data| data.get_temp::<String>(response.id)) else {
+        return;
+    };
+    if crate::utils::expression::evaluate(&text).is_none() {
+        ui.painter().rect_stroke(
+            response.rect,
+            2.0,
+            egui::Stroke::new(1.0, ui.visuals().error_fg_color),
+        );
+    }
 }
 
 pub fn number_ui_many<T>(
@@ -196,6 +249,84 @@ where
     })
 }
 
+/// Edits the value of a `NonZero*` integer, snapping zero to one and showing a warning when that happened
+/// Replaces zero, which the `NonZero*` types can't represent, by one. Negative values of the signed types are kept.
+///
+/// Returns whether the value was snapped to one.
+fn snap_non_zero<T: Num>(value: &mut T) -> bool {
+    let snapped = *value == T::from_f64(0.0);
+    if snapped {
+        *value = T::from_f64(1.0);
+    }
+    snapped
+}
+
+fn non_zero_ui<T: Num>(
+    value: &mut T,
+    unsigned: bool,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    defaults: NumberDefaults,
+) -> bool {
+    let options = NumberOptions {
+        min: unsigned.then_some(T::from_f64(1.0)),
+        ..Default::default()
+    };
+    let warning_id = id.with("snapped to one");
+
+    ui.horizontal(|ui| {
+        let changed = display_number(value, &options, ui, defaults);
+        if changed {
+            let snapped = snap_non_zero(value);
+            ui.data_mut(|data| data.insert_temp(warning_id, snapped));
+        }
+        if ui.data(|data| data.get_temp::<bool>(warning_id)) == Some(true) {
+            ui.label(RichText::new("⚠").color(ui.visuals().warn_fg_color))
+                .on_hover_text("The value can't be zero, so it was set to 1");
+        }
+        changed
+    })
+    .inner
+}
+
+macro_rules! impl_non_zero {
+    ($($ty:ty: $unsigned:literal),*) => {
+        $(
+            impl InspectorPrimitive for $ty {
+                fn ui(
+                    &mut self,
+                    ui: &mut egui::Ui,
+                    _: &dyn Any,
+                    id: egui::Id,
+                    env: InspectorUi<'_, '_>,
+                ) -> bool {
+                    let mut value = self.get();
+                    let changed = non_zero_ui(&mut value, $unsigned, ui, id, env.context.number_defaults);
+                    if changed {
+                        *self = <$ty>::new(value).expect("zero is snapped to one");
+                    }
+                    changed
+                }
+
+                fn ui_readonly(
+                    &self,
+                    ui: &mut egui::Ui,
+                    _: &dyn Any,
+                    id: egui::Id,
+                    env: InspectorUi<'_, '_>,
+                ) {
+                    self.get().ui_readonly(ui, &(), id, env);
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero!(
+    NonZeroU8: true, NonZeroU16: true, NonZeroU32: true, NonZeroU64: true, NonZeroUsize: true,
+    NonZeroI8: false, NonZeroI16: false, NonZeroI32: false, NonZeroI64: false, NonZeroIsize: false
+);
+
 impl InspectorPrimitive for bool {
     fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
         ui.checkbox(self, "").changed()
@@ -415,3 +546,34 @@ impl InspectorPrimitive for PathBuf {
         ui.text_edit_singleline(&mut self.to_string_lossy());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::{NonZeroI8, NonZeroU32};
+
+    use super::snap_non_zero;
+
+    #[test]
+    fn snap_zero_to_one() {
+        let mut value = 0u32;
+        assert!(snap_non_zero(&mut value));
+        assert_eq!(NonZeroU32::new(value), NonZeroU32::new(1));
+
+        let mut value = 0i8;
+        assert!(snap_non_zero(&mut value));
+        assert_eq!(NonZeroI8::new(value), NonZeroI8::new(1));
+    }
+
+    #[test]
+    fn keep_non_zero_values() {
+        let mut value = 7u32;
+        assert!(!snap_non_zero(&mut value));
+        assert_eq!(value, 7);
+
+        for negative in [-1i8, -100, i8::MIN] {
+            let mut value = negative;
+            assert!(!snap_non_zero(&mut value));
+            assert_eq!(value, negative);
+        }
+    }
+}
@@ -2,7 +2,7 @@
 
 use std::{any::Any, collections::HashMap};
 
-use bevy_reflect::{FromType, TypeData};
+use bevy_reflect::{FromType, TypeData, TypeRegistry};
 
 pub(crate) mod default_options;
 
@@ -105,6 +105,46 @@ where
     }
 }
 
+/// Clamp the number at `target` of the type `T` to `min..=max` and display it as a slider.
+///
+/// This is an alternative to `#[inspector(min = .., max = .., display = NumberDisplay::Slider)]` for types which don't derive [`derive@InspectorOptions`].
+/// Values which are already out of range are clamped as soon as they are edited.
+///
+/// # Panics
+///
+/// Panics if `T` isn't registered in the type registry yet.
+///
+/// ```rust
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_inspector_egui::inspector_options::{register_clamped, Target};
+/// #[derive(Reflect)]
+/// struct Alpha(f32);
+///
+/// let mut type_registry = TypeRegistry::default();
+/// type_registry.register::<Alpha>();
+/// register_clamped::<Alpha, f32>(&mut type_registry, Target::Field(0), 0.0, 1.0);
+/// ```
+pub fn register_clamped<T: 'static, N: Clone + Send + Sync + 'static>(
+    type_registry: &mut TypeRegistry,
+    target: Target,
+    min: N,
+    max: N,
+) {
+    let Some(registration) = type_registry.get_mut(std::any::TypeId::of::<T>()) else {
+        panic!("{} not registered", std::any::type_name::<T>());
+    };
+    let options = std_options::NumberOptions::between(min, max)
+        .with_display(std_options::NumberDisplay::Slider);
+    match registration.data_mut::<ReflectInspectorOptions>() {
+        Some(existing) => existing.0.insert(target, options),
+        None => {
+            let mut inspector_options = InspectorOptions::new();
+            inspector_options.insert(target, options);
+            registration.insert(ReflectInspectorOptions(inspector_options));
+        }
+    }
+}
+
 /// Helper trait for the [`derive@InspectorOptions`] macro.
 ///
 /// ```skip
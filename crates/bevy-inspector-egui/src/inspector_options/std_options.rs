@@ -88,6 +88,10 @@ impl<T> NumberOptions<T> {
         NumberOptions { speed, ..self }
     }
 
+    pub fn with_display(self, display: NumberDisplay) -> NumberOptions<T> {
+        NumberOptions { display, ..self }
+    }
+
     pub fn map<U>(&self, f: impl Fn(&T) -> U) -> NumberOptions<U> {
         NumberOptions {
             #[allow(clippy::redundant_closure)] // false positive
@@ -168,6 +168,9 @@ impl bevy_app::Plugin for DefaultInspectorConfigPlugin {
 #[doc(inline)]
 pub use inspector_options::InspectorOptions;
 
+/// The shortened type names displayed by the inspector, like `Vec<(Entity, &mut Transform)>` instead of the full paths
+pub use utils::pretty_type_name::{pretty_type_name, pretty_type_name_str};
+
 #[doc(hidden)]
 pub mod __macro_exports {
     pub use bevy_reflect;
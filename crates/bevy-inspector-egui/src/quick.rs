@@ -6,26 +6,47 @@
 //!
 //! When you want something more custom, you can use these plugins as a starting point.
 
-use std::{marker::PhantomData, sync::Mutex};
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
 
-use bevy_app::{Plugin, Update};
+use bevy_app::{App, Plugin, PostUpdate, Update};
 use bevy_asset::Asset;
 use bevy_core::TypeRegistrationPlugin;
-use bevy_ecs::{prelude::*, query::QueryFilter, schedule::BoxedCondition};
-use bevy_egui::{EguiContext, EguiPlugin};
+use bevy_ecs::{
+    prelude::*,
+    query::QueryFilter,
+    schedule::{BoxedCondition, SystemConfigs},
+};
+use bevy_egui::{EguiContext, EguiPlugin, EguiSet};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
 use bevy_reflect::Reflect;
+use bevy_transform::TransformSystem;
+use bevy_utils::HashMap;
 use bevy_window::PrimaryWindow;
-use pretty_type_name::pretty_type_name;
 
-use crate::{bevy_inspector, DefaultInspectorConfigPlugin};
+use crate::{
+    bevy_inspector::{self, EntityFilter, WorldInspectorParams},
+    utils::pretty_type_name::pretty_type_name,
+    DefaultInspectorConfigPlugin,
+};
 
-const DEFAULT_SIZE: (f32, f32) = (320., 160.);
+const DEFAULT_SIZE: (f32, f32) = (400., 240.);
 
 /// Plugin displaying a egui window with an entity list, resources and assets
 ///
 /// You can use [`WorldInspectorPlugin::run_if`] to control when the window is shown, for example
 /// in combination with `input_toggle_active`.
 ///
+/// The window only wraps [`bevy_inspector::ui_for_world_with_params`] with the params of the window, see [`WorldInspectorWindows`].
+/// You can call [`bevy_inspector::ui_for_world`] with any [`egui::Ui`] to embed the world inspector in your own layout, like a side panel.
+///
+/// Like the other plugins in this module, the window is drawn in [`PostUpdate`], after the commands of your
+/// [`Update`] systems have been applied, so it shows the components inserted in the same frame.
+/// It runs before the transform propagation, so edited [`Transform`](bevy_transform::components::Transform)s
+/// take effect in the same frame too.
+///
 /// ```no_run
 /// use bevy::prelude::*;
 /// use bevy_inspector_egui::prelude::*;
@@ -38,9 +59,51 @@ const DEFAULT_SIZE: (f32, f32) = (320., 160.);
 ///         .run();
 /// }
 /// ```
-#[derive(Default)]
+///
+/// The plugin can be added multiple times to show different entities in separate windows.
+/// Every window needs a distinct [title](WorldInspectorPlugin::with_title), and has its own params and UI state like the filters.
+/// Adding two windows with the same title panics.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::WorldInspectorPlugin;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(
+///             WorldInspectorPlugin::new()
+///                 .with_title("Cameras")
+///                 .with_entity_filter(|world, entity| world.get::<Camera>(entity).is_some()),
+///         )
+///         .add_plugins(
+///             WorldInspectorPlugin::new()
+///                 .with_title("Meshes")
+///                 .with_entity_filter(|world, entity| world.get::<Handle<Mesh>>(entity).is_some()),
+///         )
+///         .run();
+/// }
+/// ```
 pub struct WorldInspectorPlugin {
     condition: Mutex<Option<BoxedCondition>>,
+    title: String,
+    entity_filter: Option<EntityFilter>,
+    toggle_key: Option<KeyCode>,
+    default_size: egui::Vec2,
+    params: Mutex<Option<WorldInspectorParams>>,
+}
+
+impl Default for WorldInspectorPlugin {
+    fn default() -> Self {
+        Self {
+            condition: Mutex::new(None),
+            title: "World Inspector".to_string(),
+            entity_filter: None,
+            toggle_key: Some(KeyCode::F12),
+            default_size: DEFAULT_SIZE.into(),
+            params: Mutex::new(None),
+        }
+    }
 }
 
 impl WorldInspectorPlugin {
@@ -54,6 +117,124 @@ impl WorldInspectorPlugin {
         self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
         self
     }
+
+    /// Set the title of the window. Defaults to `World Inspector`.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Only show the root entities (and their children) for which `filter` returns `true`
+    pub fn with_entity_filter(
+        mut self,
+        filter: impl Fn(&World, Entity) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.entity_filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// Set the key which shows and hides the window by toggling [`WorldInspectorParams::enabled`] of its params. Defaults to `F12`.
+    pub fn with_toggle_key(mut self, key: KeyCode) -> Self {
+        self.toggle_key = Some(key);
+        self
+    }
+
+    /// Don't toggle the window using a key
+    pub fn without_toggle_key(mut self) -> Self {
+        self.toggle_key = None;
+        self
+    }
+
+    /// Set the size of the window when it is first shown. Defaults to `400x240`.
+    ///
+    /// The window can be resized afterwards, and the components of vectors like `Vec3` are stretched to fill the width.
+    pub fn with_default_size(mut self, size: impl Into<egui::Vec2>) -> Self {
+        self.default_size = size.into();
+        self
+    }
+
+    /// Use `params` for this window, instead of the defaults.
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_inspector_egui::bevy_inspector::WorldInspectorParams;
+    /// use bevy_inspector_egui::quick::WorldInspectorPlugin;
+    ///
+    /// let mut params = WorldInspectorParams::default();
+    /// params.read_only = true;
+    /// params.ignore_component::<GlobalTransform>();
+    ///
+    /// App::new()
+    ///     .add_plugins(DefaultPlugins)
+    ///     .add_plugins(WorldInspectorPlugin::new().with_params(params))
+    ///     .run();
+    /// ```
+    ///
+    /// Each window has its own params, which can be changed later using the [`WorldInspectorWindows`] resource.
+    pub fn with_params(mut self, params: WorldInspectorParams) -> Self {
+        self.params = Mutex::new(Some(params));
+        self
+    }
+}
+
+/// The [`WorldInspectorParams`] of the [`WorldInspectorPlugin`] windows, by their [title](WorldInspectorPlugin::with_title).
+///
+/// ```rust
+/// use bevy_ecs::prelude::*;
+/// use bevy_inspector_egui::quick::WorldInspectorWindows;
+///
+/// fn show_inspector(mut windows: ResMut<WorldInspectorWindows>) {
+///     if let Some(params) = windows.get_mut("World Inspector") {
+///         params.enabled = true;
+///     }
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct WorldInspectorWindows(HashMap<String, WorldInspectorParams>);
+
+impl WorldInspectorWindows {
+    /// The params of the window with the given title
+    pub fn get(&self, title: &str) -> Option<&WorldInspectorParams> {
+        self.0.get(title)
+    }
+
+    /// The params of the window with the given title
+    pub fn get_mut(&mut self, title: &str) -> Option<&mut WorldInspectorParams> {
+        self.0.get_mut(title)
+    }
+
+    /// Adds the params of a new window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there already is a window with this title.
+    fn add(&mut self, title: &str, params: WorldInspectorParams) {
+        if self.0.contains_key(title) {
+            panic!(
+                "`WorldInspectorPlugin` was added twice with the title `{title}`, but every window needs a distinct title. Use `WorldInspectorPlugin::with_title` to set it."
+            );
+        }
+        self.0.insert(title.to_owned(), params);
+    }
+}
+
+/// The toggle keys of all [`WorldInspectorPlugin`]s, with the title of their window
+#[derive(Resource, Default)]
+struct WorldInspectorToggleKeys(Vec<(KeyCode, String)>);
+
+fn toggle_world_inspector(
+    keys: Res<WorldInspectorToggleKeys>,
+    input: Option<Res<ButtonInput<KeyCode>>>,
+    mut windows: ResMut<WorldInspectorWindows>,
+) {
+    let Some(input) = input else { return };
+    for (key, title) in &keys.0 {
+        if input.just_pressed(*key) {
+            if let Some(params) = windows.get_mut(title) {
+                params.enabled = !params.enabled;
+            }
+        }
+    }
 }
 
 impl Plugin for WorldInspectorPlugin {
@@ -67,33 +248,68 @@ impl Plugin for WorldInspectorPlugin {
             app.add_plugins(EguiPlugin);
         }
 
+        let mut params = self.params.lock().unwrap().take().unwrap_or_default();
+        params.root_entity_filter = self.entity_filter.clone();
+        app.world
+            .get_resource_or_insert_with(WorldInspectorWindows::default)
+            .add(&self.title, params);
+
+        if let Some(key) = self.toggle_key {
+            if !app.world.contains_resource::<WorldInspectorToggleKeys>() {
+                app.init_resource::<WorldInspectorToggleKeys>()
+                    .add_systems(Update, toggle_world_inspector);
+            }
+            app.world
+                .resource_mut::<WorldInspectorToggleKeys>()
+                .0
+                .push((key, self.title.clone()));
+        }
+
+        let title = self.title.clone();
+        let default_size = self.default_size;
         let condition = self.condition.lock().unwrap().take();
-        let mut system = world_inspector_ui.into_configs();
+        let mut system = (move |world: &mut World| {
+            world_inspector_ui(world, &title, default_size);
+        })
+        .into_configs();
         if let Some(condition) = condition {
             system.run_if_dyn(condition);
         }
-        app.add_systems(Update, system);
+        add_ui_system(app, system);
     }
-}
 
-fn world_inspector_ui(world: &mut World) {
-    let egui_context = world
-        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
-        .get_single(world);
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
 
-    let Ok(egui_context) = egui_context else {
-        return;
-    };
-    let mut egui_context = egui_context.clone();
+fn world_inspector_ui(world: &mut World, title: &str, default_size: egui::Vec2) {
+    world.resource_scope(|world, mut windows: Mut<WorldInspectorWindows>| {
+        let Some(params) = windows.bypass_change_detection().0.get_mut(title) else {
+            return;
+        };
+        if !params.enabled {
+            return;
+        }
 
-    egui::Window::new("World Inspector")
-        .default_size(DEFAULT_SIZE)
-        .show(egui_context.get_mut(), |ui| {
-            egui::ScrollArea::both().show(ui, |ui| {
-                bevy_inspector::ui_for_world(world, ui);
-                ui.allocate_space(ui.available_size());
+        let egui_context = world
+            .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+            .get_single(world);
+
+        let Ok(egui_context) = egui_context else {
+            return;
+        };
+        let mut egui_context = egui_context.clone();
+
+        egui::Window::new(title)
+            .default_size(default_size)
+            .show(egui_context.get_mut(), |ui| {
+                egui::ScrollArea::both().show(ui, |ui| {
+                    bevy_inspector::ui_for_world_with_params(world, ui, params);
+                    ui.allocate_space(ui.available_size());
+                });
             });
-        });
+    });
 }
 
 /// Plugin displaying an egui window for a single resource.
@@ -170,7 +386,7 @@ impl<T: Resource + Reflect> Plugin for ResourceInspectorPlugin<T> {
         if let Some(condition) = condition {
             system.run_if_dyn(condition);
         }
-        app.add_systems(Update, system);
+        add_ui_system(app, system);
     }
 }
 
@@ -265,7 +481,7 @@ impl<T: States + Reflect> Plugin for StateInspectorPlugin<T> {
         if let Some(condition) = condition {
             system.run_if_dyn(condition);
         }
-        app.add_systems(Update, system);
+        add_ui_system(app, system);
     }
 }
 
@@ -349,7 +565,7 @@ impl<A: Asset + Reflect> Plugin for AssetInspectorPlugin<A> {
         if let Some(condition) = condition {
             system.run_if_dyn(condition);
         }
-        app.add_systems(Update, system);
+        add_ui_system(app, system);
     }
 }
 
@@ -432,7 +648,7 @@ where
         if let Some(condition) = condition {
             system.run_if_dyn(condition);
         }
-        app.add_systems(Update, system);
+        add_ui_system(app, system);
     }
 }
 
@@ -456,6 +672,16 @@ fn entity_query_ui<F: QueryFilter>(world: &mut World) {
         });
 }
 
+/// Schedules an inspector UI as late as possible, so that it sees the state of the current frame
+fn add_ui_system(app: &mut App, system: SystemConfigs) {
+    app.add_systems(
+        PostUpdate,
+        system
+            .before(EguiSet::ProcessOutput)
+            .before(TransformSystem::TransformPropagate),
+    );
+}
+
 fn check_default_plugins(app: &bevy_app::App, name: &str) {
     if !app.is_plugin_added::<TypeRegistrationPlugin>() {
         panic!(
@@ -467,3 +693,33 @@ fn check_default_plugins(app: &bevy_app::App, name: &str) {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::WorldInspectorWindows;
+    use crate::bevy_inspector::WorldInspectorParams;
+
+    #[test]
+    fn world_inspectors_with_distinct_titles() {
+        let mut windows = WorldInspectorWindows::default();
+        windows.add("World Inspector", WorldInspectorParams::default());
+        windows.add(
+            "Cameras",
+            WorldInspectorParams {
+                read_only: true,
+                ..Default::default()
+            },
+        );
+
+        assert!(!windows.get("World Inspector").unwrap().read_only);
+        assert!(windows.get("Cameras").unwrap().read_only);
+    }
+
+    #[test]
+    #[should_panic(expected = "every window needs a distinct title")]
+    fn world_inspectors_with_the_same_title() {
+        let mut windows = WorldInspectorWindows::default();
+        windows.add("World Inspector", WorldInspectorParams::default());
+        windows.add("World Inspector", WorldInspectorParams::default());
+    }
+}
@@ -17,7 +17,7 @@ pub fn reflect_value_no_impl(ui: &mut egui::Ui, type_name: &str) {
             FontId::monospace(12.0),
             &format!(
                 ".register_type::<{}>",
-                pretty_type_name::pretty_type_name_str(type_name)
+                crate::utils::pretty_type_name::pretty_type_name_str(type_name)
             ),
         ),
         (FontId::proportional(13.0), " or add the "),
@@ -51,7 +51,7 @@ pub fn unconstructable_variant(
 
     let qualified_variant = format!(
         "{}::{}",
-        pretty_type_name::pretty_type_name_str(type_name),
+        crate::utils::pretty_type_name::pretty_type_name_str(type_name),
         variant
     );
     vec.extend([
@@ -43,6 +43,7 @@
 //!     let mut cx = Context {
 //!         world: Some(world.into()),
 //!         queue: Some(&mut queue),
+//!         ..Default::default()
 //!     };
 //!     let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
 //!
@@ -106,6 +107,44 @@ pub fn ui_for_value_readonly(value: &dyn Reflect, ui: &mut egui::Ui, type_regist
 pub struct Context<'a> {
     pub world: Option<RestrictedWorldView<'a>>,
     pub queue: Option<&'a mut CommandQueue>,
+    /// Used by the number inspectors when no [`NumberOptions`](crate::inspector_options::std_options::NumberOptions) override them
+    pub number_defaults: NumberDefaults,
+    /// Lay out the fields of structs with more than [`WRAP_FIELDS_MIN`] fields, which are all primitive values, in wrapping rows instead of a grid
+    pub wrap_fields: bool,
+    /// Show values without an [`InspectorEguiImpl`] using their [`Debug`](std::fmt::Debug) implementation, if they reflect it
+    pub debug_fallback: bool,
+    /// Show a button next to struct fields which differ from their default value to reset them.
+    ///
+    /// The default value of a field is taken from the [`Default`] of the struct if it has one, otherwise from the [`Default`] of the field type.
+    pub reset_buttons: bool,
+    /// Highlight the labels of struct fields whose lowercase name contains this text
+    pub highlight_fields: Option<&'a str>,
+    /// The configuration of the world inspector displaying this value, used for entities displayed inside of it
+    pub world_inspector_params: Option<&'a crate::bevy_inspector::WorldInspectorParams>,
+}
+
+/// The number of primitive fields a struct needs to have more than for [`Context::wrap_fields`] to apply
+pub const WRAP_FIELDS_MIN: usize = 4;
+
+/// Default drag speed and precision of numbers, see [`WorldInspectorParams`](crate::bevy_inspector::WorldInspectorParams::drag_speed)
+#[derive(Clone, Copy, Debug)]
+pub struct NumberDefaults {
+    /// How much the value changes per pixel dragged
+    pub drag_speed: f32,
+    /// The number of decimals to show, or `None` to choose them based on the drag speed
+    pub decimals: Option<usize>,
+    /// Evaluate arithmetic expressions like `pi/2` typed into number fields
+    pub allow_expressions: bool,
+}
+
+impl Default for NumberDefaults {
+    fn default() -> Self {
+        Self {
+            drag_speed: 0.1,
+            decimals: None,
+            allow_expressions: false,
+        }
+    }
 }
 
 /// Function which will be executed for every field recursively, which can be used to skip regular traversal.
@@ -356,14 +395,14 @@ impl InspectorUi<'_, '_> {
             TypeInfo::Array(info) => {
                 errors::no_multiedit(
                     ui,
-                    &pretty_type_name::pretty_type_name_str(info.type_path()),
+                    &crate::utils::pretty_type_name::pretty_type_name_str(info.type_path()),
                 );
                 false
             }
             TypeInfo::Map(info) => {
                 errors::no_multiedit(
                     ui,
-                    &pretty_type_name::pretty_type_name_str(info.type_path()),
+                    &crate::utils::pretty_type_name::pretty_type_name_str(info.type_path()),
                 );
                 false
             }
@@ -395,6 +434,12 @@ fn ui_for_empty_list(ui: &mut egui::Ui) -> bool {
     ui_for_empty_collection(ui, "(Empty List)")
 }
 
+fn is_option(info: &EnumInfo) -> bool {
+    let type_path_table = info.type_path_table();
+    type_path_table.module_path() == Some("core::option")
+        && type_path_table.ident() == Some("Option")
+}
+
 fn ui_for_list_controls(ui: &mut egui::Ui, index: usize, len: usize) -> Option<ListOp> {
     use ListOp::*;
     let mut op = None;
@@ -442,10 +487,15 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) -> bool {
+        let wrap = wraps_fields(self.context.wrap_fields, value.iter_fields());
+        let field_defaults = match self.context.reset_buttons {
+            true => struct_field_defaults(self.type_registry, value),
+            false => Vec::new(),
+        };
         let mut changed = false;
-        Grid::new(id).show(ui, |ui| {
+        fields_layout(ui, id, wrap, |ui| {
             for i in 0..value.field_len() {
-                ui.label(value.name_at(i).unwrap());
+                self.field_label(ui, value.name_at(i).unwrap());
                 let field = value.field_at_mut(i).unwrap();
                 changed |= self.ui_for_reflect_with_options(
                     field,
@@ -453,7 +503,12 @@ impl InspectorUi<'_, '_> {
                     id.with(i),
                     inspector_options_struct_field(options, i),
                 );
-                ui.end_row();
+                if let Some(Some(default)) = field_defaults.get(i) {
+                    changed |= reset_field_button(value.field_at_mut(i).unwrap(), &**default, ui);
+                }
+                if !wrap {
+                    ui.end_row();
+                }
             }
         });
         changed
@@ -466,9 +521,10 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) {
-        Grid::new(id).show(ui, |ui| {
+        let wrap = wraps_fields(self.context.wrap_fields, value.iter_fields());
+        fields_layout(ui, id, wrap, |ui| {
             for i in 0..value.field_len() {
-                ui.label(value.name_at(i).unwrap());
+                self.field_label(ui, value.name_at(i).unwrap());
                 let field = value.field_at(i).unwrap();
                 self.ui_for_reflect_readonly_with_options(
                     field,
@@ -476,7 +532,9 @@ impl InspectorUi<'_, '_> {
                     id.with(i),
                     inspector_options_struct_field(options, i),
                 );
-                ui.end_row();
+                if !wrap {
+                    ui.end_row();
+                }
             }
         });
     }
@@ -1258,6 +1316,32 @@ impl InspectorUi<'_, '_> {
     ) -> Option<(usize, DynamicEnum)> {
         let mut changed_variant = None;
 
+        if is_option(info) {
+            // `Option<T>` is displayed as a checkbox instead of a `None`/`Some` dropdown
+            let mut is_some = info.variant_names()[active_variant_idx] == "Some";
+            let some_variant = info.variant("Some").expect("Option has a Some variant");
+            let some_is_constructable = variant_constructable(self.type_registry, some_variant);
+
+            let mut response = ui
+                .add_enabled_ui(is_some || some_is_constructable.is_ok(), |ui| {
+                    ui.checkbox(&mut is_some, "")
+                })
+                .inner;
+            if let Err(fields) = some_is_constructable {
+                response = response.on_disabled_hover_ui(|ui| {
+                    errors::unconstructable_variant(ui, info.type_path(), "Some", &fields);
+                });
+            }
+            if response.changed() {
+                let variant_name = if is_some { "Some" } else { "None" };
+                let variant = info.variant(variant_name).expect("Option has a variant");
+                if let Ok(dynamic_enum) = self.construct_default_variant(variant, ui) {
+                    changed_variant = Some((info.index_of(variant_name).unwrap(), dynamic_enum));
+                }
+            }
+            return changed_variant;
+        }
+
         ui.horizontal_top(|ui| {
             egui::ComboBox::new(id.with("select"), "")
                 .selected_text(info.variant_names()[active_variant_idx])
@@ -1321,10 +1405,18 @@ impl InspectorUi<'_, '_> {
     ) {
         ui.vertical(|ui| {
             let active_variant = value.variant_name();
+            let value_is_option = matches!(
+                value.get_represented_type_info(),
+                Some(TypeInfo::Enum(info)) if is_option(info)
+            );
             ui.add_enabled_ui(false, |ui| {
-                egui::ComboBox::new(id, "")
-                    .selected_text(active_variant)
-                    .show_ui(ui, |_| {})
+                if value_is_option {
+                    ui.checkbox(&mut (active_variant == "Some"), "");
+                } else {
+                    egui::ComboBox::new(id, "")
+                        .selected_text(active_variant)
+                        .show_ui(ui, |_| {});
+                }
             });
 
             let always_show_label = matches!(value.variant_type(), VariantType::Struct);
@@ -1364,7 +1456,9 @@ impl InspectorUi<'_, '_> {
         _id: egui::Id,
         _options: &dyn Any,
     ) -> bool {
-        errors::reflect_value_no_impl(ui, value.reflect_short_type_path());
+        if !self.debug_fallback_ui(value, ui) {
+            errors::reflect_value_no_impl(ui, value.reflect_short_type_path());
+        }
         false
     }
 
@@ -1375,7 +1469,9 @@ impl InspectorUi<'_, '_> {
         _id: egui::Id,
         _options: &dyn Any,
     ) {
-        errors::reflect_value_no_impl(ui, value.reflect_short_type_path());
+        if !self.debug_fallback_ui(value, ui) {
+            errors::reflect_value_no_impl(ui, value.reflect_short_type_path());
+        }
     }
 
     fn ui_for_value_many(
@@ -1388,6 +1484,89 @@ impl InspectorUi<'_, '_> {
         errors::reflect_value_no_impl(ui, info.type_path());
         false
     }
+
+    /// Shows the name of a struct field, highlighted if it matches [`Context::highlight_fields`]
+    fn field_label(&self, ui: &mut egui::Ui, name: &str) {
+        let highlighted = self
+            .context
+            .highlight_fields
+            .is_some_and(|text| name.to_lowercase().contains(text));
+        if highlighted {
+            ui.label(egui::RichText::new(name).background_color(ui.visuals().selection.bg_fill));
+        } else {
+            ui.label(name);
+        }
+    }
+
+    /// Shows the [`Debug`](std::fmt::Debug) representation of the value as read-only text, see [`Context::debug_fallback`].
+    ///
+    /// Returns `false` if the value doesn't reflect its `Debug` implementation.
+    fn debug_fallback_ui(&self, value: &dyn Reflect, ui: &mut egui::Ui) -> bool {
+        // long enough for small structs, while keeping large collections from flooding the UI
+        const MAX_LEN: usize = 200;
+
+        if !self.context.debug_fallback {
+            return false;
+        }
+        // the default `Reflect::debug` of values only prints the type path
+        let mut debug = format!("{value:?}");
+        if debug == format!("Reflect({})", value.reflect_type_path()) {
+            return false;
+        }
+        crate::utils::truncate(&mut debug, MAX_LEN);
+
+        ui.label(egui::RichText::new(format!("🔒 {debug}")).monospace())
+            .on_hover_text(format!(
+                "{} has no InspectorEguiImpl, so it is shown read-only using its Debug implementation",
+                value.reflect_short_type_path()
+            ));
+        true
+    }
+}
+
+/// The values the fields of `value` are reset to, see [`Context::reset_buttons`].
+///
+/// Uses the fields of the [`Default`] of the struct if it has one, so that for example the `scale` of a `Transform`
+/// is reset to `Vec3::ONE` instead of `Vec3::ZERO`, and falls back to the [`Default`] of each field type otherwise.
+fn struct_field_defaults(
+    type_registry: &TypeRegistry,
+    value: &dyn Struct,
+) -> Vec<Option<Box<dyn Reflect>>> {
+    let reflect_default = |type_info: Option<&TypeInfo>| {
+        type_registry.get_type_data::<ReflectDefault>(type_info?.type_id())
+    };
+
+    let struct_default = reflect_default(value.get_represented_type_info()).map(|d| d.default());
+    if let Some(ReflectRef::Struct(struct_default)) =
+        struct_default.as_ref().map(|d| d.reflect_ref())
+    {
+        return struct_default
+            .iter_fields()
+            .map(|field| Some(field.clone_value()))
+            .collect();
+    }
+
+    value
+        .iter_fields()
+        .map(|field| reflect_default(field.get_represented_type_info()).map(|d| d.default()))
+        .collect()
+}
+
+/// Shows a button to reset the field to `default` if it differs from it, see [`Context::reset_buttons`]
+fn reset_field_button(field: &mut dyn Reflect, default: &dyn Reflect, ui: &mut egui::Ui) -> bool {
+    if field.reflect_partial_eq(default) == Some(true) {
+        return false;
+    }
+    // not focusable, so that tab moves between the fields without stopping at their reset buttons
+    let button = egui::Button::new("↺").small().sense(egui::Sense {
+        focusable: false,
+        ..egui::Sense::click()
+    });
+    if ui.add(button).on_hover_text("Reset to default").clicked() {
+        field.apply(default);
+        return true;
+    }
+    false
 }
 
 impl<'a, 'c> InspectorUi<'a, 'c> {
@@ -1450,6 +1629,30 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
     }
 }
 
+/// Whether the fields should be laid out in wrapping rows, see [`Context::wrap_fields`]
+fn wraps_fields<'a>(wrap_fields: bool, fields: impl Iterator<Item = &'a dyn Reflect>) -> bool {
+    if !wrap_fields {
+        return false;
+    }
+    let mut count = 0;
+    for field in fields {
+        if !matches!(field.reflect_ref(), ReflectRef::Value(_)) {
+            return false;
+        }
+        count += 1;
+    }
+    count > WRAP_FIELDS_MIN
+}
+
+/// Shows the fields in a grid, or in wrapping rows if `wrap` is set
+fn fields_layout(ui: &mut egui::Ui, id: egui::Id, wrap: bool, f: impl FnOnce(&mut egui::Ui)) {
+    if wrap {
+        ui.horizontal_wrapped(f);
+    } else {
+        Grid::new(id).show(ui, f);
+    }
+}
+
 #[must_use]
 fn maybe_grid(
     i: usize,
@@ -1567,3 +1770,47 @@ fn inspector_options_enum_variant_field<'a>(
 fn or(a: bool, b: bool) -> bool {
     a || b
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::Vec3;
+    use bevy_reflect::{Struct, TypeRegistry};
+    use bevy_transform::components::Transform;
+
+    use super::struct_field_defaults;
+
+    #[test]
+    fn reset_field_to_struct_default() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Transform>();
+
+        let mut transform = Transform::from_scale(Vec3::splat(5.0));
+        transform.translation = Vec3::X;
+        let defaults = struct_field_defaults(&type_registry, &transform);
+
+        let index = (0..transform.field_len())
+            .position(|i| transform.name_at(i) == Some("scale"))
+            .unwrap();
+        let scale = defaults[index].as_deref().unwrap();
+        transform.field_mut("scale").unwrap().apply(scale);
+        assert_eq!(transform.scale, Vec3::ONE);
+        assert_eq!(transform.translation, Vec3::X);
+    }
+
+    #[test]
+    fn reset_field_to_field_type_default() {
+        #[derive(bevy_reflect::Reflect)]
+        struct NoDefault {
+            scale: Vec3,
+        }
+
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<NoDefault>();
+        type_registry.register::<Vec3>();
+
+        let value = NoDefault { scale: Vec3::ONE };
+        let defaults = struct_field_defaults(&type_registry, &value);
+        let scale = defaults[0].as_deref().unwrap();
+        assert_eq!(scale.downcast_ref::<Vec3>(), Some(&Vec3::ZERO));
+    }
+}
@@ -379,6 +379,7 @@ impl<'w> RestrictedWorldView<'w> {
     }
 
     // SAFETY: must ensure distinct access
+    #[allow(clippy::mut_from_ref)]
     pub(crate) unsafe fn get_entity_component_reflect_unchecked(
         &self,
         entity: Entity,
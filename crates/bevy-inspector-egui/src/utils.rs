@@ -11,7 +11,146 @@ pub fn mut_untyped_split<'a>(mut mut_untyped: MutUntyped<'a>) -> (PtrMut<'a>, im
     (ptr, move || mut_untyped.set_changed())
 }
 
+pub mod pretty_type_name {
+    /// Returns a shorter version of [`std::any::type_name`], like `Vec<(Entity, &mut Transform)>`
+    pub fn pretty_type_name<T: ?Sized>() -> String {
+        pretty_type_name_str(std::any::type_name::<T>())
+    }
+
+    /// Same as [`pretty_type_name()`], but for type name strings
+    pub fn pretty_type_name_str(type_name: &str) -> String {
+        let mut short_name = String::new();
+
+        // `pretty_type_name::pretty_type_name_str` drops prefixes like `&mut ` together with the module path,
+        // so the paths separated by the characters below (in generic parameters, tuples or arrays) are shortened individually.
+        let mut remainder = type_name;
+        while let Some(index) = remainder.find(['<', '>', '(', ')', '[', ']', ',', ';']) {
+            let (path, new_remainder) = remainder.split_at(index);
+            push_shortened_path(&mut short_name, path);
+
+            let character = new_remainder.chars().next().unwrap();
+            short_name.push(character);
+            remainder = &new_remainder[1..];
+            if character == ',' || character == ';' {
+                short_name.push(' ');
+                remainder = remainder.trim_start();
+            }
+        }
+        push_shortened_path(&mut short_name, remainder);
+
+        short_name
+    }
+
+    /// Pushes the shortened path, keeping prefixes like `&mut ` or `dyn ` and every bound of trait objects like `dyn A + B`
+    fn push_shortened_path(short_name: &mut String, path: &str) {
+        let mut rest = path;
+        while let Some(stripped) = ["&", "mut ", "*const ", "*mut ", "dyn "]
+            .iter()
+            .find_map(|prefix| rest.strip_prefix(prefix))
+        {
+            rest = stripped;
+        }
+        short_name.push_str(&path[..path.len() - rest.len()]);
+
+        for (i, bound) in rest.split(" + ").enumerate() {
+            if i > 0 {
+                short_name.push_str(" + ");
+            }
+            short_name.push_str(&pretty_type_name::pretty_type_name_str(bound));
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::pretty_type_name_str;
+
+        #[test]
+        fn shorten_name_generic() {
+            assert_eq!(
+                pretty_type_name_str("bevy_asset::handle::Handle<bevy_pbr::StandardMaterial>"),
+                "Handle<StandardMaterial>"
+            );
+        }
+
+        #[test]
+        fn shorten_name_tuple() {
+            assert_eq!(
+                pretty_type_name_str("(alloc::string::String, (glam::Vec3, glam::Quat))"),
+                "(String, (Vec3, Quat))"
+            );
+        }
+
+        #[test]
+        fn shorten_name_tuple_elements() {
+            assert_eq!(
+                pretty_type_name_str("(alloc::string::String, glam::f32::vec3::Vec3)"),
+                "(String, Vec3)"
+            );
+            assert_eq!(
+                pretty_type_name_str(
+                    "bevy_ecs::system::query::Query<(bevy_ecs::entity::Entity, &bevy_transform::components::transform::Transform)>"
+                ),
+                "Query<(Entity, &Transform)>"
+            );
+            assert_eq!(pretty_type_name_str("()"), "()");
+        }
+
+        #[test]
+        fn shorten_name_reference() {
+            assert_eq!(
+                pretty_type_name_str("&bevy_transform::components::Transform"),
+                "&Transform"
+            );
+            assert_eq!(
+                pretty_type_name_str("&mut bevy_transform::components::Transform"),
+                "&mut Transform"
+            );
+            assert_eq!(
+                pretty_type_name_str(
+                    "bevy_ecs::system::Query<(bevy_ecs::entity::Entity, &mut bevy_transform::components::Transform)>"
+                ),
+                "Query<(Entity, &mut Transform)>"
+            );
+        }
+
+        #[test]
+        fn shorten_name_slice_and_array() {
+            assert_eq!(pretty_type_name_str("[u8]"), "[u8]");
+            assert_eq!(pretty_type_name_str("&[glam::Vec3]"), "&[Vec3]");
+            assert_eq!(pretty_type_name_str("[glam::Vec3; 4]"), "[Vec3; 4]");
+            assert_eq!(
+                pretty_type_name_str("&mut [&dyn bevy_reflect::Reflect; 2]"),
+                "&mut [&dyn Reflect; 2]"
+            );
+        }
+
+        #[test]
+        fn shorten_name_trait_object() {
+            assert_eq!(
+                pretty_type_name_str("alloc::boxed::Box<dyn bevy_reflect::Reflect>"),
+                "Box<dyn Reflect>"
+            );
+            assert_eq!(
+                pretty_type_name_str(
+                    "&dyn bevy_reflect::Reflect + core::marker::Send + core::marker::Sync"
+                ),
+                "&dyn Reflect + Send + Sync"
+            );
+        }
+
+        #[test]
+        fn shorten_name_closure() {
+            assert_eq!(
+                pretty_type_name_str("my_game::setup::{{closure}}"),
+                "setup::{{closure}}"
+            );
+        }
+    }
+}
+
 pub mod guess_entity_name {
+    use std::borrow::Cow;
+
     use bevy_core::Name;
     use bevy_ecs::{archetype::Archetype, prelude::*, world::unsafe_world_cell::UnsafeWorldCell};
 
@@ -58,6 +197,34 @@ pub mod guess_entity_name {
         entity: Entity,
         archetype: &Archetype,
     ) -> String {
+        match guess_name_from_archetype(world, archetype) {
+            Some(name) => format!("{name} ({entity:?})"),
+            None => format!("Entity ({entity:?})"),
+        }
+    }
+
+    /// Like [`guess_entity_name`], but without the entity id suffix
+    pub(crate) fn guess_entity_name_without_id(world: &World, entity: Entity) -> Cow<'_, str> {
+        match world.get_entity(entity) {
+            Some(entity_ref) => {
+                if let Some(name) = entity_ref.get::<Name>() {
+                    return Cow::Borrowed(name.as_str());
+                }
+
+                let name = guess_name_from_archetype(
+                    world.as_unsafe_world_cell_readonly(),
+                    entity_ref.archetype(),
+                );
+                Cow::Borrowed(name.unwrap_or("Entity"))
+            }
+            None => Cow::Borrowed("Entity (inexistent)"),
+        }
+    }
+
+    fn guess_name_from_archetype(
+        world: UnsafeWorldCell<'_>,
+        archetype: &Archetype,
+    ) -> Option<&'static str> {
         #[rustfmt::skip]
         let associations = &[
             ("bevy_window::window::PrimaryWindow", "Primary Window"),
@@ -71,20 +238,176 @@ pub mod guess_entity_name {
             ("bevy_window::window::Window", "Window"),
         ];
 
-        let type_names = archetype.components().filter_map(|id| {
+        let mut type_names = archetype.components().filter_map(|id| {
             let name = world.components().get_info(id)?.name();
             Some(name)
         });
 
-        for component_type in type_names {
-            if let Some(name) = associations
+        type_names.find_map(|component_type| {
+            associations
                 .iter()
                 .find_map(|&(name, matches)| (component_type == name).then_some(matches))
-            {
-                return format!("{name} ({entity:?})");
+        })
+    }
+}
+
+/// Cuts `text` off after `max_chars` characters, ending it with `…` if anything was removed
+pub fn truncate(text: &mut String, max_chars: usize) {
+    if let Some((index, _)) = text.char_indices().nth(max_chars) {
+        text.truncate(index);
+        text.push('…');
+    }
+}
+
+pub mod expression {
+    //! Small arithmetic expressions for number fields, see [`WorldInspectorParams::allow_expressions`](crate::bevy_inspector::WorldInspectorParams::allow_expressions)
+
+    /// Evaluates an expression like `pi/2` or `1024 * 2`.
+    ///
+    /// Supports numbers, `+`, `-`, `*`, `/`, `%`, `^` (power), parentheses and the constants `pi`, `tau` and `e`.
+    pub fn evaluate(text: &str) -> Option<f64> {
+        let mut parser = Parser {
+            text: text.as_bytes(),
+            pos: 0,
+        };
+        let value = parser.sum()?;
+        parser.skip_whitespace();
+        (parser.pos == parser.text.len() && value.is_finite()).then_some(value)
+    }
+
+    struct Parser<'a> {
+        text: &'a [u8],
+        pos: usize,
+    }
+
+    impl Parser<'_> {
+        fn skip_whitespace(&mut self) {
+            while self.text.get(self.pos).is_some_and(u8::is_ascii_whitespace) {
+                self.pos += 1;
+            }
+        }
+
+        /// Consumes the next non-whitespace character if it is one of `chars`
+        fn eat(&mut self, chars: &[u8]) -> Option<u8> {
+            self.skip_whitespace();
+            let c = *self.text.get(self.pos)?;
+            chars.contains(&c).then(|| {
+                self.pos += 1;
+                c
+            })
+        }
+
+        fn sum(&mut self) -> Option<f64> {
+            let mut value = self.product()?;
+            while let Some(op) = self.eat(b"+-") {
+                let rhs = self.product()?;
+                value = if op == b'+' { value + rhs } else { value - rhs };
+            }
+            Some(value)
+        }
+
+        fn product(&mut self) -> Option<f64> {
+            let mut value = self.unary()?;
+            while let Some(op) = self.eat(b"*/%") {
+                let rhs = self.unary()?;
+                value = match op {
+                    b'*' => value * rhs,
+                    b'/' => value / rhs,
+                    _ => value % rhs,
+                };
+            }
+            Some(value)
+        }
+
+        /// Negation binds weaker than powers, so that `-2^2` is `-4`
+        fn unary(&mut self) -> Option<f64> {
+            match self.eat(b"+-") {
+                Some(b'-') => Some(-self.unary()?),
+                Some(_) => self.unary(),
+                None => self.power(),
+            }
+        }
+
+        fn power(&mut self) -> Option<f64> {
+            let base = self.atom()?;
+            // right associative, so that `2^3^2` is `2^9`
+            match self.eat(b"^") {
+                Some(_) => Some(base.powf(self.unary()?)),
+                None => Some(base),
             }
         }
 
-        format!("Entity ({entity:?})")
+        fn atom(&mut self) -> Option<f64> {
+            if self.eat(b"(").is_some() {
+                let value = self.sum()?;
+                self.eat(b")")?;
+                return Some(value);
+            }
+
+            self.skip_whitespace();
+            let start = self.pos;
+            let is_number = |c: &u8| c.is_ascii_digit() || *c == b'.';
+            let is_ident = |c: &u8| c.is_ascii_alphabetic();
+            if self.text.get(self.pos).is_some_and(is_number) {
+                while self.text.get(self.pos).is_some_and(is_number) {
+                    self.pos += 1;
+                }
+                // exponent, like `1e-3`
+                if self.text.get(self.pos) == Some(&b'e') {
+                    let exponent_start = self.pos;
+                    self.pos += 1;
+                    if matches!(self.text.get(self.pos), Some(b'+' | b'-')) {
+                        self.pos += 1;
+                    }
+                    if !self.text.get(self.pos).is_some_and(u8::is_ascii_digit) {
+                        self.pos = exponent_start;
+                    }
+                    while self.text.get(self.pos).is_some_and(u8::is_ascii_digit) {
+                        self.pos += 1;
+                    }
+                }
+                return std::str::from_utf8(&self.text[start..self.pos])
+                    .ok()?
+                    .parse()
+                    .ok();
+            }
+
+            while self.text.get(self.pos).is_some_and(is_ident) {
+                self.pos += 1;
+            }
+            match &self.text[start..self.pos] {
+                b"pi" => Some(std::f64::consts::PI),
+                b"tau" => Some(std::f64::consts::TAU),
+                b"e" => Some(std::f64::consts::E),
+                _ => None,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::evaluate;
+
+        #[test]
+        fn evaluate_arithmetic() {
+            assert_eq!(evaluate("1024*2"), Some(2048.0));
+            assert_eq!(evaluate(" 1 + 2 * 3 "), Some(7.0));
+            assert_eq!(evaluate("(1 + 2) * 3"), Some(9.0));
+            assert_eq!(evaluate("-2^2"), Some(-4.0));
+            assert_eq!(evaluate("2^3^2"), Some(512.0));
+            assert_eq!(evaluate("7 % 4 - -1"), Some(4.0));
+            assert_eq!(evaluate("1.5e3 / 2"), Some(750.0));
+            assert_eq!(evaluate("pi/2"), Some(std::f64::consts::FRAC_PI_2));
+        }
+
+        #[test]
+        fn evaluate_invalid() {
+            assert_eq!(evaluate(""), None);
+            assert_eq!(evaluate("1 +"), None);
+            assert_eq!(evaluate("(1"), None);
+            assert_eq!(evaluate("1 2"), None);
+            assert_eq!(evaluate("foo"), None);
+            assert_eq!(evaluate("1/0"), None);
+        }
     }
 }